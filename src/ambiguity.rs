@@ -0,0 +1,203 @@
+//! How clear-cut a decomposition is compared to its alternatives.
+
+use std::ops::Range;
+
+use crate::{decompose_ranked, Constituent, DecompositionConfig, DecompositionError};
+
+/// How clearly the decomposition returned by [`decompound`](crate::decompound) beats
+/// its runner-up, see [`AmbiguityReport`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Ambiguity {
+    /// There is no runner-up, or it ranks clearly lower.
+    Unique,
+    /// The runner-up ranks lower, but only within the margin set via
+    /// [`DecompositionConfig::with_near_tie_margin`].
+    NearTie,
+    /// The runner-up ranks just as high; the decomposition returned merely was found
+    /// first.
+    Tie,
+}
+
+/// The decomposition of a word, with how it compares to its runner-up, as returned by
+/// [`decompound_with_ambiguity`].
+///
+/// Alternatives only count if they split at different positions, not just differ in
+/// the form of constituents (for example titlecased or not).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AmbiguityReport {
+    constituents: Vec<String>,
+    runner_up: Option<Vec<String>>,
+    margin: f64,
+    ambiguity: Ambiguity,
+}
+
+impl AmbiguityReport {
+    /// The decomposition, as returned by [`decompound`](crate::decompound).
+    #[must_use]
+    pub fn constituents(&self) -> &[String] {
+        &self.constituents
+    }
+
+    /// The best alternative decomposition, if any.
+    #[must_use]
+    pub fn runner_up(&self) -> Option<&[String]> {
+        self.runner_up.as_deref()
+    }
+
+    /// By how much the decomposition outranks its runner-up: the difference in their
+    /// scores if a scorer is set (see [`DecompositionConfig::with_scorer`]), otherwise in
+    /// their number of constituents. Infinite if there is no runner-up, or it ranks
+    /// lower for splitting at fewer boundary hints (see
+    /// [`DecompositionOptions::BOUNDARY_HINTS`](crate::DecompositionOptions::BOUNDARY_HINTS)).
+    #[must_use]
+    pub fn margin(&self) -> f64 {
+        self.margin
+    }
+
+    /// How ambiguous the word is.
+    #[must_use]
+    pub fn ambiguity(&self) -> Ambiguity {
+        self.ambiguity
+    }
+}
+
+/// Like [`decompound`](crate::decompound), but also reports whether the word is
+/// ambiguous, that is whether another decomposition ranks (almost) as high as the one
+/// returned.
+///
+/// ```
+/// use decompound::{decompound_with_ambiguity, Ambiguity, DecompositionOptions};
+///
+/// let is_valid_single_word = |w: &str| [
+///     "Wach",
+///     "Stube",
+///     "Wachs",
+///     "Tube",
+///     "Haus",
+///     "Boot",
+/// ].contains(&w);
+///
+/// let report = decompound_with_ambiguity(
+///     "Wachstube",
+///     &is_valid_single_word,
+///     DecompositionOptions::TRY_TITLECASE_SUFFIX,
+/// ).unwrap();
+///
+/// assert_eq!(report.constituents(), ["Wach", "Stube"]);
+/// assert_eq!(report.ambiguity(), Ambiguity::Tie);
+/// assert_eq!(report.runner_up().unwrap(), ["Wachs", "Tube"]);
+///
+/// let report = decompound_with_ambiguity(
+///     "Hausboot",
+///     &is_valid_single_word,
+///     DecompositionOptions::TRY_TITLECASE_SUFFIX,
+/// ).unwrap();
+///
+/// assert_eq!(report.ambiguity(), Ambiguity::Unique);
+/// assert_eq!(report.runner_up(), None);
+/// ```
+///
+/// For words split on hyphens (see
+/// [`DecompositionOptions::SPLIT_HYPHENATED`](crate::DecompositionOptions::SPLIT_HYPHENATED)),
+/// the most ambiguous part decides, and the runner-up differs in that part only.
+///
+/// ## Errors
+///
+/// Same as [`decompound`](crate::decompound).
+pub fn decompound_with_ambiguity<'a>(
+    word: impl AsRef<str>,
+    is_valid_single_word: &impl Fn(&str) -> bool,
+    config: impl Into<DecompositionConfig<'a>>,
+) -> Result<AmbiguityReport, DecompositionError> {
+    let ranked = decompose_ranked(word.as_ref(), is_valid_single_word, &config.into())?;
+    let forms = |split: Vec<Constituent>| split.into_iter().map(|c| c.form).collect();
+
+    Ok(AmbiguityReport {
+        constituents: forms(ranked.best),
+        runner_up: ranked.runner_up.map(forms),
+        margin: ranked.margin,
+        ambiguity: ranked.ambiguity,
+    })
+}
+
+/// The best decomposition found, and how it compares to the runner-up.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Ranked {
+    pub(crate) best: Vec<Constituent>,
+    pub(crate) runner_up: Option<Vec<Constituent>>,
+    pub(crate) margin: f64,
+    pub(crate) ambiguity: Ambiguity,
+}
+
+impl Ranked {
+    /// A decomposition without any alternatives.
+    pub(crate) fn unique(best: Vec<Constituent>) -> Self {
+        Self {
+            best,
+            runner_up: None,
+            margin: f64::INFINITY,
+            ambiguity: Ambiguity::Unique,
+        }
+    }
+
+    /// Maps the spans of all constituents through `f`.
+    pub(crate) fn map_spans(self, f: impl Fn(&Range<usize>) -> Range<usize>) -> Self {
+        let map = |split: Vec<Constituent>| {
+            split
+                .into_iter()
+                .map(|c| Constituent {
+                    span: f(&c.span),
+                    ..c
+                })
+                .collect()
+        };
+
+        Self {
+            best: map(self.best),
+            runner_up: self.runner_up.map(map),
+            ..self
+        }
+    }
+
+    /// Joins the decompositions of consecutive parts of a word. The most ambiguous part
+    /// decides how ambiguous the whole is, and is the only one differing in the
+    /// runner-up.
+    pub(crate) fn join(parts: &[Ranked]) -> Self {
+        let mut decisive: Option<usize> = None;
+        for (i, part) in parts.iter().enumerate() {
+            let is_more_ambiguous = decisive.map_or(true, |d| {
+                let current = &parts[d];
+                part.ambiguity > current.ambiguity
+                    || (part.ambiguity == current.ambiguity && part.margin < current.margin)
+            });
+
+            if is_more_ambiguous {
+                decisive = Some(i);
+            }
+        }
+
+        let best: Vec<Constituent> = parts.iter().flat_map(|p| p.best.clone()).collect();
+        let runner_up = decisive.and_then(|d| {
+            let alternative = parts[d].runner_up.as_ref()?;
+
+            Some(
+                parts
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, p)| if i == d { alternative } else { &p.best })
+                    .cloned()
+                    .collect(),
+            )
+        });
+
+        match decisive.map(|d| &parts[d]) {
+            Some(part) => Self {
+                best,
+                runner_up,
+                margin: part.margin,
+                ambiguity: part.ambiguity,
+            },
+            None => Self::unique(best),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! Presets for [`DecompositionConfig::with_boundary_predicate`](crate::DecompositionConfig::with_boundary_predicate).
+
+/// Letter sequences spelling a single sound in German, which a boundary never cuts
+/// through.
+const GERMAN_MULTIGRAPHS: &[&str] = &["sch", "ch", "ck", "qu"];
+
+const GERMAN_VOWELS: &[char] = &['a', 'e', 'i', 'o', 'u', 'ä', 'ö', 'ü', 'y'];
+
+/// Boundary predicate for German: forbids cutting through `sch`, `ch`, `ck` and `qu`,
+/// and requires constituents on both sides of the boundary to contain a vowel.
+///
+/// ```
+/// use decompound::{decompound, german_boundary, DecompositionConfig, DecompositionOptions};
+///
+/// let is_valid_single_word = |w: &str| ["Tisc", "Hecke", "Tisch", "Ecke"].contains(&w);
+///
+/// assert_eq!(
+///     decompound(
+///         "Tischecke",
+///         &is_valid_single_word,
+///         DecompositionOptions::TRY_TITLECASE_SUFFIX,
+///     ).unwrap(),
+///     vec!["Tisc", "Hecke"]
+/// );
+///
+/// assert_eq!(
+///     decompound(
+///         "Tischecke",
+///         &is_valid_single_word,
+///         DecompositionConfig::new(DecompositionOptions::TRY_TITLECASE_SUFFIX)
+///             .with_boundary_predicate(&german_boundary),
+///     ).unwrap(),
+///     vec!["Tisch", "Ecke"]
+/// );
+/// ```
+#[must_use]
+pub fn german_boundary(left: &str, right: &str) -> bool {
+    let left = left.to_lowercase();
+    let right = right.to_lowercase();
+
+    let cuts_multigraph = GERMAN_MULTIGRAPHS.iter().any(|multigraph| {
+        multigraph
+            .char_indices()
+            .skip(1)
+            .any(|(i, _)| left.ends_with(&multigraph[..i]) && right.starts_with(&multigraph[i..]))
+    });
+
+    !cuts_multigraph && left.contains(GERMAN_VOWELS) && right.contains(GERMAN_VOWELS)
+}
@@ -0,0 +1,82 @@
+//! Limits on the work done by a single decomposition.
+
+use std::{cell::Cell, sync::atomic::Ordering, time::Instant};
+
+use log::trace;
+
+use crate::DecompositionConfig;
+
+/// Tracks the work done by a single call to [`decompound`](crate::decompound) against
+/// the limits of its configuration. Once exhausted, it stays exhausted.
+#[derive(Debug)]
+pub(crate) struct Budget<'s, 'c> {
+    config: &'s DecompositionConfig<'c>,
+    validator_calls: Cell<usize>,
+    explored_splits: Cell<usize>,
+    exhausted: Cell<bool>,
+}
+
+impl<'s, 'c> Budget<'s, 'c> {
+    pub(crate) fn new(config: &'s DecompositionConfig<'c>) -> Self {
+        Self {
+            config,
+            validator_calls: Cell::new(0),
+            explored_splits: Cell::new(0),
+            exhausted: Cell::new(false),
+        }
+    }
+
+    /// Accounts for a call of the validity check, returning whether it may be made.
+    pub(crate) fn spend_validator_call(&self) -> bool {
+        self.spend(&self.validator_calls, self.config.max_validator_calls)
+    }
+
+    /// Accounts for exploring a split point, returning whether it may be explored.
+    pub(crate) fn spend_explored_split(&self) -> bool {
+        self.spend(&self.explored_splits, self.config.max_explored_splits)
+    }
+
+    /// Whether the budget was exhausted at any point so far.
+    pub(crate) fn is_exhausted(&self) -> bool {
+        self.exhausted.get()
+    }
+
+    fn spend(&self, counter: &Cell<usize>, limit: Option<usize>) -> bool {
+        counter.set(counter.get() + 1);
+
+        if limit.map_or(false, |limit| counter.get() > limit) {
+            trace!("Search budget exhausted after {} units", counter.get() - 1);
+            self.exhausted.set(true);
+        }
+
+        self.remains()
+    }
+
+    /// Checks the limits not tied to any single unit of work, remembering if anything
+    /// ran out.
+    fn remains(&self) -> bool {
+        if self.exhausted.get() {
+            return false;
+        }
+
+        let past_deadline = self
+            .config
+            .deadline
+            .map_or(false, |deadline| Instant::now() >= deadline);
+        let cancelled = self
+            .config
+            .cancellation
+            .map_or(false, |flag| flag.load(Ordering::Relaxed));
+        let aborted = self.config.aborted.map_or(false, Cell::get);
+
+        if past_deadline || cancelled || aborted {
+            trace!(
+                "Search aborted (past deadline: {past_deadline}, cancelled: {cancelled}, \
+                 aborted: {aborted})"
+            );
+            self.exhausted.set(true);
+        }
+
+        !self.exhausted.get()
+    }
+}
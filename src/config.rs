@@ -0,0 +1,593 @@
+//! Configuration beyond what fits into [`DecompositionOptions`].
+
+use std::{cell::Cell, fmt, sync::atomic::AtomicBool, time::Instant};
+
+use crate::{
+    Bracketing, DecompositionOptions, Exceptions, Forms, FrequencyLexicon, Headedness,
+    HyphenationPatterns, Position, Role, SearchObserver, SearchStrategy,
+};
+
+/// See [`DecompositionConfig::with_boundary_predicate`].
+type BoundaryPredicate<'a> = &'a dyn Fn(&str, &str) -> bool;
+
+/// See [`DecompositionConfig::with_transform`].
+pub(crate) type Transform<'a> = &'a dyn Fn(&str, Position) -> Forms<'_>;
+
+/// See [`DecompositionConfig::with_lemmatizer`].
+type Lemmatizer<'a> = &'a dyn Fn(&str) -> Option<String>;
+
+/// Whether a valid form is allowed in a role, see
+/// [`decompound_with_metadata`](crate::decompound_with_metadata).
+type RoleFilter<'a> = &'a dyn Fn(&str, Role) -> bool;
+
+/// Priority of the lexicon a valid form was found in, if any, see
+/// [`decompound_with_lexicons`](crate::decompound_with_lexicons).
+type Priority<'a> = &'a dyn Fn(&str) -> Option<i32>;
+
+/// Ranks decompositions, see [`DecompositionConfig::with_scorer`].
+#[derive(Clone, Copy)]
+pub(crate) enum Scorer<'a> {
+    Frequencies(&'a FrequencyLexicon),
+    Custom(&'a dyn Fn(&[&str]) -> f64),
+}
+
+impl Scorer<'_> {
+    pub(crate) fn score(&self, constituents: &[&str]) -> f64 {
+        match self {
+            Scorer::Frequencies(frequencies) => frequencies.geometric_mean(constituents),
+            Scorer::Custom(score) => score(constituents),
+        }
+    }
+}
+
+impl fmt::Debug for Scorer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Scorer::Frequencies(frequencies) => {
+                f.debug_tuple("Frequencies").field(frequencies).finish()
+            }
+            Scorer::Custom(_) => f.write_str("Custom(<scorer>)"),
+        }
+    }
+}
+
+/// Full configuration for [`decompound`](crate::decompound).
+///
+/// [`DecompositionOptions`] cover all plain on/off switches and convert into this type,
+/// so they can be passed directly wherever a configuration is expected. Construct this
+/// type when anything beyond those switches is needed:
+///
+/// ```
+/// use decompound::{decompound, DecompositionConfig, DecompositionOptions};
+///
+/// let is_valid_single_word = |w: &str| ["Haus", "Boot"].contains(&w);
+///
+/// let config = DecompositionConfig::new(
+///     DecompositionOptions::TRY_TITLECASE_SUFFIX,
+/// ).with_mandatory_boundary_hints(true);
+///
+/// assert!(decompound("Hausboot", &is_valid_single_word, &config).is_err());
+/// assert_eq!(
+///     decompound("Haus\u{00AD}boot", &is_valid_single_word, &config).unwrap(),
+///     vec!["Haus", "Boot"]
+/// );
+/// ```
+#[derive(Clone)]
+pub struct DecompositionConfig<'a> {
+    pub(crate) options: DecompositionOptions,
+    pub(crate) mandatory_boundary_hints: bool,
+    pub(crate) hyphenation_patterns: Option<&'a HyphenationPatterns>,
+    pub(crate) boundary_predicate: Option<BoundaryPredicate<'a>>,
+    pub(crate) min_constituent_len: usize,
+    pub(crate) max_constituents: Option<usize>,
+    pub(crate) max_depth: Option<usize>,
+    pub(crate) min_word_len: usize,
+    pub(crate) exceptions: Option<&'a Exceptions>,
+    pub(crate) strategy: SearchStrategy,
+    pub(crate) max_validator_calls: Option<usize>,
+    pub(crate) max_explored_splits: Option<usize>,
+    pub(crate) deadline: Option<Instant>,
+    pub(crate) cancellation: Option<&'a AtomicBool>,
+    /// Set once the search is to be given up on, as a lookup failed, see
+    /// [`try_decompound`](crate::try_decompound).
+    pub(crate) aborted: Option<&'a Cell<bool>>,
+    pub(crate) scorer: Option<Scorer<'a>>,
+    pub(crate) near_tie_margin: f64,
+    pub(crate) headedness: Headedness,
+    pub(crate) bracketing: Bracketing,
+    pub(crate) observer: Option<&'a dyn SearchObserver>,
+    pub(crate) transforms: Vec<Transform<'a>>,
+    pub(crate) lemmatizer: Option<Lemmatizer<'a>>,
+    pub(crate) lemmatize_modifiers: bool,
+    pub(crate) role_filter: Option<RoleFilter<'a>>,
+    pub(crate) priority: Option<Priority<'a>>,
+}
+
+impl<'a> DecompositionConfig<'a> {
+    /// Creates a configuration from `options`, with everything else left at its
+    /// default.
+    #[must_use]
+    pub fn new(options: DecompositionOptions) -> Self {
+        Self {
+            options,
+            mandatory_boundary_hints: false,
+            hyphenation_patterns: None,
+            boundary_predicate: None,
+            min_constituent_len: 1,
+            max_constituents: None,
+            max_depth: None,
+            min_word_len: 0,
+            exceptions: None,
+            strategy: SearchStrategy::Exhaustive,
+            max_validator_calls: None,
+            max_explored_splits: None,
+            deadline: None,
+            cancellation: None,
+            aborted: None,
+            scorer: None,
+            near_tie_margin: 0.1,
+            headedness: Headedness::Right,
+            bracketing: Bracketing::Search,
+            observer: None,
+            transforms: Vec::new(),
+            lemmatizer: None,
+            lemmatize_modifiers: false,
+            role_filter: None,
+            priority: None,
+        }
+    }
+
+    /// The plain switches in effect.
+    #[must_use]
+    pub fn options(&self) -> &DecompositionOptions {
+        &self.options
+    }
+
+    /// Split *only* at boundary hints, never anywhere else. A word without any hints is
+    /// therefore never decomposed. Implies
+    /// [`DecompositionOptions::BOUNDARY_HINTS`].
+    #[must_use]
+    pub fn with_mandatory_boundary_hints(mut self, mandatory: bool) -> Self {
+        self.mandatory_boundary_hints = mandatory;
+        self
+    }
+
+    /// Only split words where `patterns` allow hyphenation, plus at boundary hints, if
+    /// any. Each hyphen-separated part of a word (see
+    /// [`DecompositionOptions::SPLIT_HYPHENATED`]) is hyphenated on its own.
+    ///
+    /// ```
+    /// use decompound::{
+    ///     decompound, DecompositionConfig, DecompositionOptions, HyphenationPatterns,
+    /// };
+    ///
+    /// let is_valid_single_word = |w: &str| [
+    ///     "Druck",
+    ///     "Erzeugnis",
+    ///     "Drucker",
+    ///     "Zeugnis",
+    /// ].contains(&w);
+    ///
+    /// // Without patterns: ambiguous, first valid split wins
+    /// assert_eq!(
+    ///     decompound(
+    ///         "Druckerzeugnis",
+    ///         &is_valid_single_word,
+    ///         DecompositionOptions::TRY_TITLECASE_SUFFIX,
+    ///     ).unwrap(),
+    ///     vec!["Druck", "Erzeugnis"]
+    /// );
+    ///
+    /// // With patterns, allowing `Dru-cker-zeug-nis`
+    /// let patterns = HyphenationPatterns::parse("u1c r1z g1n");
+    /// assert_eq!(
+    ///     decompound(
+    ///         "Druckerzeugnis",
+    ///         &is_valid_single_word,
+    ///         DecompositionConfig::new(DecompositionOptions::TRY_TITLECASE_SUFFIX)
+    ///             .with_hyphenation_patterns(&patterns),
+    ///     ).unwrap(),
+    ///     vec!["Drucker", "Zeugnis"]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_hyphenation_patterns(mut self, patterns: &'a HyphenationPatterns) -> Self {
+        self.hyphenation_patterns = Some(patterns);
+        self
+    }
+
+    /// Consult `predicate` before exploring any split point. It is called with the
+    /// candidate constituent ending at the split point, and everything after it (of the
+    /// current hyphen-separated part, if split on), both as spelled in the word but with
+    /// any boundary hints removed. Return `false` to skip the split point.
+    ///
+    /// See [`german_boundary`](crate::german_boundary) for a preset.
+    ///
+    /// ```
+    /// use decompound::{decompound, DecompositionConfig, DecompositionOptions};
+    ///
+    /// let is_valid_single_word = |w: &str| ["a", "b", "c", "ab", "bc"].contains(&w);
+    /// let never_after_a = |left: &str, _: &str| !left.ends_with('a');
+    ///
+    /// assert_eq!(
+    ///     decompound("abc", &is_valid_single_word, DecompositionConfig::default()).unwrap(),
+    ///     vec!["a", "bc"]
+    /// );
+    /// assert_eq!(
+    ///     decompound(
+    ///         "abc",
+    ///         &is_valid_single_word,
+    ///         DecompositionConfig::default().with_boundary_predicate(&never_after_a),
+    ///     ).unwrap(),
+    ///     vec!["ab", "c"]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_boundary_predicate(mut self, predicate: &'a dyn Fn(&str, &str) -> bool) -> Self {
+        self.boundary_predicate = Some(predicate);
+        self
+    }
+
+    /// Reject constituents shorter than `chars` characters, no matter what the
+    /// validity check says. Does not apply to words which are not decomposed at all.
+    ///
+    /// ```
+    /// use decompound::{decompound, DecompositionConfig, DecompositionOptions};
+    ///
+    /// let is_valid_single_word = |w: &str| [
+    ///     "Entnahme",
+    ///     "Stelle",
+    ///     "St",
+    ///     "Elle",
+    /// ].contains(&w);
+    ///
+    /// let options = DecompositionOptions::TRY_TITLECASE_SUFFIX | DecompositionOptions::SHATTER;
+    ///
+    /// assert_eq!(
+    ///     decompound("Entnahmestelle", &is_valid_single_word, options.clone()).unwrap(),
+    ///     vec!["Entnahme", "St", "Elle"]
+    /// );
+    /// assert_eq!(
+    ///     decompound(
+    ///         "Entnahmestelle",
+    ///         &is_valid_single_word,
+    ///         DecompositionConfig::new(options).with_min_constituent_len(3),
+    ///     ).unwrap(),
+    ///     vec!["Entnahme", "Stelle"]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_min_constituent_len(mut self, chars: usize) -> Self {
+        self.min_constituent_len = chars;
+        self
+    }
+
+    /// Reject decompositions into more than `max` constituents, counting all parts of
+    /// hyphenated words (see [`DecompositionOptions::SPLIT_HYPHENATED`]) together.
+    #[must_use]
+    pub fn with_max_constituents(mut self, max: usize) -> Self {
+        self.max_constituents = Some(max);
+        self
+    }
+
+    /// Limit how deep the search recurses into suffixes. At a depth of `0`, words are
+    /// only ever split in two; each additional level allows one more constituent. The
+    /// limit applies to each hyphen-separated part of a word on its own.
+    #[must_use]
+    pub fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Do not attempt to decompose words shorter than `chars` characters (not counting
+    /// boundary hints). Such words are only checked for validity as a whole.
+    #[must_use]
+    pub fn with_min_word_len(mut self, chars: usize) -> Self {
+        self.min_word_len = chars;
+        self
+    }
+
+    /// Consult `exceptions` before searching, see [`Exceptions`].
+    #[must_use]
+    pub fn with_exceptions(mut self, exceptions: &'a Exceptions) -> Self {
+        self.exceptions = Some(exceptions);
+        self
+    }
+
+    /// Search according to `strategy`, see [`SearchStrategy`].
+    #[must_use]
+    pub fn with_strategy(mut self, strategy: SearchStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Give up after calling the validity check `max` times, returning
+    /// [`DecompositionError::BudgetExhausted`].
+    ///
+    /// ```
+    /// use decompound::{decompound, DecompositionConfig, DecompositionError, DecompositionOptions};
+    ///
+    /// let is_valid_single_word = |w: &str| ["Donau", "Dampf", "Schiff"].contains(&w);
+    ///
+    /// assert_eq!(
+    ///     decompound(
+    ///         "Donaudampfschiff",
+    ///         &is_valid_single_word,
+    ///         DecompositionConfig::new(DecompositionOptions::TRY_TITLECASE_SUFFIX)
+    ///             .with_max_validator_calls(5),
+    ///     ).unwrap_err(),
+    ///     DecompositionError::BudgetExhausted(None)
+    /// );
+    /// ```
+    ///
+    /// [`DecompositionError::BudgetExhausted`]: crate::DecompositionError::BudgetExhausted
+    #[must_use]
+    pub fn with_max_validator_calls(mut self, max: usize) -> Self {
+        self.max_validator_calls = Some(max);
+        self
+    }
+
+    /// Give up after exploring `max` split points, returning
+    /// [`DecompositionError::BudgetExhausted`](crate::DecompositionError::BudgetExhausted).
+    #[must_use]
+    pub fn with_max_explored_splits(mut self, max: usize) -> Self {
+        self.max_explored_splits = Some(max);
+        self
+    }
+
+    /// Give up once `deadline` has passed, returning
+    /// [`DecompositionError::BudgetExhausted`](crate::DecompositionError::BudgetExhausted).
+    #[must_use]
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Give up once `flag` is set, returning
+    /// [`DecompositionError::BudgetExhausted`](crate::DecompositionError::BudgetExhausted).
+    /// The flag is checked throughout the search, so it can be set from another thread
+    /// to cancel a search in progress.
+    #[must_use]
+    pub fn with_cancellation_flag(mut self, flag: &'a AtomicBool) -> Self {
+        self.cancellation = Some(flag);
+        self
+    }
+
+    /// Rank decompositions by `score`, highest first, instead of by their number of
+    /// constituents. `score` is handed the forms of all constituents of a
+    /// decomposition. Preference for splits at boundary hints (see
+    /// [`DecompositionOptions::BOUNDARY_HINTS`]) still takes precedence, and of equally
+    /// scored decompositions, the first one found wins.
+    ///
+    /// The word itself competes as well, handed to `score` as its only constituent: if
+    /// valid as a single word and scored at least as high as all of its decompositions,
+    /// it is not decomposed ([`DecompositionError::SingleWord`]). Only complete
+    /// decompositions of the word are compared, so searching exhaustively (see
+    /// [`SearchStrategy::Exhaustive`]) enumerates all of them, keeping more than just
+    /// the best one of each rest of the word.
+    ///
+    /// [`DecompositionError::SingleWord`]: crate::DecompositionError::SingleWord
+    ///
+    /// ```
+    /// use decompound::{decompound, DecompositionConfig, DecompositionOptions};
+    ///
+    /// let is_valid_single_word = |w: &str| ["Wach", "Stube", "Wachs", "Tube"].contains(&w);
+    /// let longest_first = |constituents: &[&str]| constituents[0].len() as f64;
+    ///
+    /// assert_eq!(
+    ///     decompound(
+    ///         "Wachstube",
+    ///         &is_valid_single_word,
+    ///         DecompositionConfig::new(DecompositionOptions::TRY_TITLECASE_SUFFIX)
+    ///             .with_scorer(&longest_first),
+    ///     ).unwrap(),
+    ///     vec!["Wachs", "Tube"]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_scorer(mut self, score: &'a dyn Fn(&[&str]) -> f64) -> Self {
+        self.scorer = Some(Scorer::Custom(score));
+        self
+    }
+
+    /// Rank decompositions by the geometric mean of the corpus frequencies of their
+    /// constituents, with the word itself competing by its own frequency, see
+    /// [`FrequencyLexicon`]. Replaces any scorer set via
+    /// [`DecompositionConfig::with_scorer`].
+    #[must_use]
+    pub fn with_frequencies(mut self, frequencies: &'a FrequencyLexicon) -> Self {
+        self.scorer = Some(Scorer::Frequencies(frequencies));
+        self
+    }
+
+    /// Consider a decomposition as good as tied with its runner-up if it outranks it by
+    /// no more than `relative` times its own score (see [`AmbiguityReport::margin`]).
+    /// Defaults to `0.1`, that is ten percent.
+    ///
+    /// [`AmbiguityReport::margin`]: crate::AmbiguityReport::margin
+    #[must_use]
+    pub fn with_near_tie_margin(mut self, relative: f64) -> Self {
+        self.near_tie_margin = relative;
+        self
+    }
+
+    /// Which constituent is the head, see [`decompound_headed`]. Defaults to
+    /// [`Headedness::Right`].
+    ///
+    /// [`decompound_headed`]: crate::decompound_headed
+    #[must_use]
+    pub fn with_headedness(mut self, headedness: Headedness) -> Self {
+        self.headedness = headedness;
+        self
+    }
+
+    /// How to bracket constituents, see [`decompound_tree`]. Defaults to
+    /// [`Bracketing::Search`].
+    ///
+    /// [`decompound_tree`]: crate::decompound_tree
+    #[must_use]
+    pub fn with_bracketing(mut self, bracketing: Bracketing) -> Self {
+        self.bracketing = bracketing;
+        self
+    }
+
+    /// Notify `observer` of everything happening during the search, for example to
+    /// collect [`SearchStats`](crate::SearchStats).
+    #[must_use]
+    pub fn with_observer(mut self, observer: &'a dyn SearchObserver) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// In *addition* to its form as is, look up each constituent in the forms
+    /// `transform` produces from it, for example to undo spelling changes compounding
+    /// brings about. May be called repeatedly, adding transforms to those set before,
+    /// which are tried in order after the built-in [`titlecase`](crate::titlecase) one
+    /// (see [`DecompositionOptions::TRY_TITLECASE_SUFFIX`]). Transforms apply to the
+    /// constituent as is, not to each other's forms; empty forms are ignored.
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use decompound::{decompound, DecompositionConfig, DecompositionOptions, Forms, Position};
+    ///
+    /// let is_valid_single_word = |w: &str| ["rot", "Wein"].contains(&w);
+    ///
+    /// // Modifiers might be adjectives, which the lexicon lists in lowercase.
+    /// fn lowercase_modifiers(w: &str, position: Position) -> Forms<'_> {
+    ///     if position == Position::Final {
+    ///         Box::new(std::iter::empty())
+    ///     } else {
+    ///         Box::new(std::iter::once(Cow::Owned(w.to_lowercase())))
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(
+    ///     decompound(
+    ///         "Rotwein",
+    ///         &is_valid_single_word,
+    ///         DecompositionConfig::new(DecompositionOptions::TRY_TITLECASE_SUFFIX)
+    ///             .with_transform(&lowercase_modifiers),
+    ///     ).unwrap(),
+    ///     vec!["rot", "Wein"]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_transform(mut self, transform: &'a dyn Fn(&str, Position) -> Forms<'_>) -> Self {
+        self.transforms.push(transform);
+        self
+    }
+
+    /// Lemmatize the final constituent with `lemmatizer`, like for indexing: its lemma
+    /// is available via [`Constituent::lemma`](crate::Constituent::lemma), along with
+    /// the form as found. If the validity check rejects the form, but accepts its
+    /// lemma, the constituent is accepted by it, for lexicons without inflected forms.
+    /// `lemmatizer` is called with forms as looked up (see
+    /// [`DecompositionConfig::with_transform`]), and returns `None` for forms it does
+    /// not know. With [`DecompositionOptions::SPLIT_HYPHENATED`], the final constituent
+    /// of each hyphen-separated part is lemmatized.
+    ///
+    /// ```
+    /// use decompound::{decompound_spans, DecompositionConfig, DecompositionOptions};
+    ///
+    /// let is_valid_single_word = |w: &str| ["Kinder", "Garten"].contains(&w);
+    /// let lemmatizer = |w: &str| w.strip_suffix('s').map(ToOwned::to_owned);
+    ///
+    /// let constituents = decompound_spans(
+    ///     "Kindergartens",
+    ///     &is_valid_single_word,
+    ///     DecompositionConfig::new(DecompositionOptions::TRY_TITLECASE_SUFFIX)
+    ///         .with_lemmatizer(&lemmatizer),
+    /// ).unwrap();
+    ///
+    /// assert_eq!(constituents[1].form(), "Gartens");
+    /// assert_eq!(constituents[1].lemma(), Some("Garten"));
+    /// // Modifiers are not lemmatized
+    /// assert_eq!(constituents[0].lemma(), None);
+    /// ```
+    #[must_use]
+    pub fn with_lemmatizer(mut self, lemmatizer: &'a dyn Fn(&str) -> Option<String>) -> Self {
+        self.lemmatizer = Some(lemmatizer);
+        self
+    }
+
+    /// Lemmatize modifiers as well, not just the final constituent (see
+    /// [`DecompositionConfig::with_lemmatizer`]). In many languages, modifiers are not
+    /// inflected, so this mostly leads to spurious splits.
+    #[must_use]
+    pub fn with_lemmatized_modifiers(mut self, lemmatize: bool) -> Self {
+        self.lemmatize_modifiers = lemmatize;
+        self
+    }
+
+    /// Whether boundary hints are to be stripped before lookup.
+    pub(crate) fn strips_boundary_hints(&self) -> bool {
+        self.mandatory_boundary_hints || self.options.contains(DecompositionOptions::BOUNDARY_HINTS)
+    }
+}
+
+impl fmt::Debug for DecompositionConfig<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecompositionConfig")
+            .field("options", &self.options)
+            .field("mandatory_boundary_hints", &self.mandatory_boundary_hints)
+            .field("hyphenation_patterns", &self.hyphenation_patterns)
+            .field(
+                "boundary_predicate",
+                &self.boundary_predicate.map(|_| "<predicate>"),
+            )
+            .field("min_constituent_len", &self.min_constituent_len)
+            .field("max_constituents", &self.max_constituents)
+            .field("max_depth", &self.max_depth)
+            .field("min_word_len", &self.min_word_len)
+            .field("exceptions", &self.exceptions)
+            .field("strategy", &self.strategy)
+            .field("max_validator_calls", &self.max_validator_calls)
+            .field("max_explored_splits", &self.max_explored_splits)
+            .field("deadline", &self.deadline)
+            .field("cancellation", &self.cancellation)
+            .field("aborted", &self.aborted)
+            .field("scorer", &self.scorer)
+            .field("near_tie_margin", &self.near_tie_margin)
+            .field("headedness", &self.headedness)
+            .field("bracketing", &self.bracketing)
+            .field("observer", &self.observer.map(|_| "<observer>"))
+            .field(
+                "transforms",
+                &self
+                    .transforms
+                    .iter()
+                    .map(|_| "<transform>")
+                    .collect::<Vec<_>>(),
+            )
+            .field("lemmatizer", &self.lemmatizer.map(|_| "<lemmatizer>"))
+            .field("lemmatize_modifiers", &self.lemmatize_modifiers)
+            .field("role_filter", &self.role_filter.map(|_| "<role filter>"))
+            .field("priority", &self.priority.map(|_| "<priority>"))
+            .finish()
+    }
+}
+
+impl Default for DecompositionConfig<'_> {
+    fn default() -> Self {
+        Self::new(DecompositionOptions::empty())
+    }
+}
+
+impl From<DecompositionOptions> for DecompositionConfig<'_> {
+    fn from(options: DecompositionOptions) -> Self {
+        Self::new(options)
+    }
+}
+
+impl From<&DecompositionOptions> for DecompositionConfig<'_> {
+    fn from(options: &DecompositionOptions) -> Self {
+        Self::new(options.clone())
+    }
+}
+
+impl<'a> From<&DecompositionConfig<'a>> for DecompositionConfig<'a> {
+    fn from(config: &DecompositionConfig<'a>) -> Self {
+        config.clone()
+    }
+}
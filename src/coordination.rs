@@ -0,0 +1,175 @@
+//! Expansion of coordinated compounds with an elided, shared part.
+
+use log::trace;
+
+use crate::{decompose, DecompositionConfig, DecompositionError};
+
+/// Words joining the members of a coordinated phrase. They are dropped from the
+/// expansion.
+const CONJUNCTIONS: &[&str] = &[
+    "und",
+    "oder",
+    "sowie",
+    "bzw.",
+    "beziehungsweise",
+    "respektive",
+    "resp.",
+    "&",
+];
+
+/// Punctuation surrounding members, like a trailing full stop. Hyphens are not part of
+/// it, as they mark elided parts.
+const PUNCTUATION: &[char] = &[
+    '.', ';', ':', '!', '?', '(', ')', '[', ']', '"', '\'', '„', '“', '”', '»', '«',
+];
+
+/// A single member of a coordinated phrase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Member<'a> {
+    /// A full word, like `Ausgang`.
+    Complete(&'a str),
+    /// A modifier with its head elided, like `Ein-`. Stored *without* the hyphen.
+    MissingHead(&'a str),
+    /// A head with its modifier elided, like `-hefte`. Stored *without* the hyphen.
+    MissingModifier(&'a str),
+    /// Nothing but hyphens, like a dash set off by spaces in `Haus - und Hoftür`.
+    Malformed,
+}
+
+impl<'a> Member<'a> {
+    fn parse(token: &'a str) -> Self {
+        if token.chars().all(|c| c == '-') {
+            return Member::Malformed;
+        }
+
+        match (token.strip_suffix('-'), token.strip_prefix('-')) {
+            (Some(modifier), _) if !modifier.is_empty() => Member::MissingHead(modifier),
+            (_, Some(head)) if !head.is_empty() => Member::MissingModifier(head),
+            _ => Member::Complete(token),
+        }
+    }
+
+    fn complete(self) -> Option<&'a str> {
+        match self {
+            Member::Complete(word) => Some(word),
+            _ => None,
+        }
+    }
+}
+
+/// Expands a coordinated phrase whose members share an elided part, such as `Ein- und
+/// Ausgang` (*entrance and exit*), into the full compound words.
+///
+/// Members ending in a hyphen borrow their head from the next complete member,
+/// members starting with a hyphen borrow their modifier from the previous complete
+/// member. Which part of the complete member is shared is found by running
+/// [`decompound`](crate::decompound) on it: a missing head is everything *after* its
+/// first constituent, a missing modifier everything *before* its last one. Members may
+/// be separated by whitespace, commas, slashes and common conjunctions (`und`, `oder`,
+/// `bzw.`, ...); the latter are dropped, as is punctuation surrounding members (like
+/// in `Haus- und Hofhund.`).
+///
+/// ```
+/// use decompound::{expand_coordination, DecompositionOptions};
+///
+/// let is_valid_single_word = |w: &str| [
+///     "Ein",
+///     "Aus",
+///     "Gang",
+///     "Vor",
+///     "Nach",
+///     "Teile",
+///     "Schul",
+///     "Bücher",
+/// ].contains(&w);
+///
+/// assert_eq!(
+///     expand_coordination(
+///         "Ein- und Ausgang",
+///         &is_valid_single_word,
+///         DecompositionOptions::TRY_TITLECASE_SUFFIX,
+///     ).unwrap(),
+///     vec!["Eingang", "Ausgang"]
+/// );
+///
+/// assert_eq!(
+///     expand_coordination(
+///         "Vor-/Nachteile",
+///         &is_valid_single_word,
+///         DecompositionOptions::TRY_TITLECASE_SUFFIX,
+///     ).unwrap(),
+///     vec!["Vorteile", "Nachteile"]
+/// );
+///
+/// assert_eq!(
+///     expand_coordination(
+///         "Schulbücher und -hefte",
+///         &is_valid_single_word,
+///         DecompositionOptions::TRY_TITLECASE_SUFFIX,
+///     ).unwrap(),
+///     vec!["Schulbücher", "Schulhefte"]
+/// );
+/// ```
+///
+/// ## Errors
+///
+/// [`DecompositionError::NothingValid`] is returned if an elided part cannot be
+/// recovered: there is no complete member to borrow from, or that member is not a
+/// compound word itself (like `Ausgang` in a dictionary lacking `Aus` or `Gang`). It is
+/// also returned if a member is nothing but a hyphen, as in `Haus - und Hoftür`.
+/// Complete members are not validated otherwise, and returned as-is.
+pub fn expand_coordination<'a>(
+    phrase: impl AsRef<str>,
+    is_valid_single_word: &impl Fn(&str) -> bool,
+    config: impl Into<DecompositionConfig<'a>>,
+) -> Result<Vec<String>, DecompositionError> {
+    let config = config.into();
+
+    let members: Vec<Member<'_>> = phrase
+        .as_ref()
+        .split(|c: char| c.is_whitespace() || c == ',' || c == '/')
+        // Conjunctions might end in a full stop themselves, so check before stripping.
+        .filter(|token| !CONJUNCTIONS.contains(token))
+        .map(|token| token.trim_matches(PUNCTUATION))
+        .filter(|token| !token.is_empty() && !CONJUNCTIONS.contains(token))
+        .map(Member::parse)
+        .collect();
+
+    trace!("Coordinated phrase has members: {members:?}");
+
+    members
+        .iter()
+        .enumerate()
+        .map(|(i, member)| match *member {
+            Member::Complete(word) => Ok(word.to_owned()),
+            Member::Malformed => Err(DecompositionError::NothingValid),
+            Member::MissingHead(modifier) => {
+                let donor = members[i + 1..]
+                    .iter()
+                    .find_map(|m| m.complete())
+                    .ok_or(DecompositionError::NothingValid)?;
+
+                let first = decompose(donor, is_valid_single_word, &config)
+                    .ok()
+                    .and_then(|constituents| constituents.first().cloned())
+                    .ok_or(DecompositionError::NothingValid)?;
+
+                Ok(format!("{modifier}{}", &donor[first.span.end..]))
+            }
+            Member::MissingModifier(head) => {
+                let donor = members[..i]
+                    .iter()
+                    .rev()
+                    .find_map(|m| m.complete())
+                    .ok_or(DecompositionError::NothingValid)?;
+
+                let last = decompose(donor, is_valid_single_word, &config)
+                    .ok()
+                    .and_then(|constituents| constituents.last().cloned())
+                    .ok_or(DecompositionError::NothingValid)?;
+
+                Ok(format!("{}{head}", &donor[..last.span.start]))
+            }
+        })
+        .collect()
+}
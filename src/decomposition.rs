@@ -0,0 +1,263 @@
+//! Decompositions as a head and its modifiers.
+
+use std::{cell::RefCell, collections::HashMap};
+
+use crate::{decompose, Constituent, DecompositionConfig, DecompositionError};
+
+/// Which constituent of a compound is its head, the one determining its grammatical
+/// properties (like gender) and semantic class, set via
+/// [`DecompositionConfig::with_headedness`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Headedness {
+    /// The last constituent is the head, as in German, Dutch and the Scandinavian
+    /// languages: a *Hausboot* is a kind of boat.
+    #[default]
+    Right,
+    /// The first constituent is the head, as in Vietnamese or Romance-style
+    /// compounds: a *timbre-poste* is a kind of stamp.
+    Left,
+}
+
+/// The role of a constituent in a compound, for rules over lexicon metadata, see
+/// [`decompound_with_metadata`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// The head, see [`Headedness`].
+    Head,
+    /// Any other constituent.
+    Modifier,
+}
+
+/// A decomposition, as returned by [`decompound_headed`], telling apart its head from
+/// its modifiers. As returned by [`decompound_with_metadata`], it carries lexicon
+/// metadata of type `M` for each constituent.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Decomposition<M = ()> {
+    constituents: Vec<Constituent>,
+    metadata: Vec<Option<M>>,
+    headedness: Headedness,
+}
+
+impl<M> Decomposition<M> {
+    /// All constituents, in order of appearance in the word.
+    #[must_use]
+    pub fn constituents(&self) -> &[Constituent] {
+        &self.constituents
+    }
+
+    /// The headedness the head was identified by.
+    #[must_use]
+    pub fn headedness(&self) -> Headedness {
+        self.headedness
+    }
+
+    /// The head.
+    #[must_use]
+    pub fn head(&self) -> &Constituent {
+        &self.constituents[self.head_index()]
+    }
+
+    /// Metadata of all constituents, in order of appearance in the word. `None` for
+    /// constituents the lexicon has none for (like ones found valid by
+    /// [`DecompositionOptions::GENDER_INCLUSIVE`](crate::DecompositionOptions::GENDER_INCLUSIVE)
+    /// or given by [`Exceptions`](crate::Exceptions)), and all of them if not looked
+    /// up at all, as by [`decompound_headed`].
+    #[must_use]
+    pub fn metadata(&self) -> &[Option<M>] {
+        &self.metadata
+    }
+
+    /// Metadata of the compound as a whole, which is that of its head.
+    #[must_use]
+    pub fn compound_metadata(&self) -> Option<&M> {
+        self.metadata[self.head_index()].as_ref()
+    }
+
+    fn head_index(&self) -> usize {
+        match self.headedness {
+            Headedness::Right => self.constituents.len().saturating_sub(1),
+            Headedness::Left => 0,
+        }
+    }
+
+    /// All constituents but the head, in order of appearance in the word.
+    #[must_use]
+    pub fn modifiers(&self) -> &[Constituent] {
+        let len = self.constituents.len();
+
+        match self.headedness {
+            Headedness::Right => &self.constituents[..len.saturating_sub(1)],
+            Headedness::Left => &self.constituents[len.min(1)..],
+        }
+    }
+
+    /// Looks up the head's form in `lexicon`, for metadata like its gender.
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use decompound::{decompound_headed, DecompositionOptions};
+    ///
+    /// let genders = HashMap::from([("Haus", "neuter"), ("Tür", "feminine")]);
+    /// let is_valid_single_word = |w: &str| genders.contains_key(w);
+    ///
+    /// let decomposition = decompound_headed(
+    ///     "Haustür",
+    ///     &is_valid_single_word,
+    ///     DecompositionOptions::TRY_TITLECASE_SUFFIX,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(
+    ///     decomposition.head_metadata(|w| genders.get(w).copied()),
+    ///     Some("feminine")
+    /// );
+    /// ```
+    pub fn head_metadata<T>(&self, lexicon: impl FnOnce(&str) -> Option<T>) -> Option<T> {
+        lexicon(self.head().form())
+    }
+}
+
+/// Like [`decompound_spans`](crate::decompound_spans), but identifies the head of the
+/// compound according to [`DecompositionConfig::with_headedness`].
+///
+/// ```
+/// use decompound::{decompound_headed, DecompositionConfig, DecompositionOptions, Headedness};
+///
+/// let is_valid_single_word = |w: &str| ["Donau", "Dampf", "Schiff"].contains(&w);
+///
+/// let decomposition = decompound_headed(
+///     "Donaudampfschiff",
+///     &is_valid_single_word,
+///     DecompositionOptions::TRY_TITLECASE_SUFFIX,
+/// ).unwrap();
+///
+/// assert_eq!(decomposition.head().form(), "Schiff");
+/// assert_eq!(
+///     decomposition.modifiers().iter().map(|c| c.form()).collect::<Vec<_>>(),
+///     vec!["Donau", "Dampf"]
+/// );
+///
+/// let is_valid_single_word = |w: &str| ["timbre", "poste"].contains(&w);
+///
+/// let decomposition = decompound_headed(
+///     "timbre-poste",
+///     &is_valid_single_word,
+///     DecompositionConfig::new(DecompositionOptions::SPLIT_HYPHENATED)
+///         .with_headedness(Headedness::Left),
+/// ).unwrap();
+///
+/// assert_eq!(decomposition.head().form(), "timbre");
+/// ```
+///
+/// For words split on hyphens (see
+/// [`DecompositionOptions::SPLIT_HYPHENATED`](crate::DecompositionOptions::SPLIT_HYPHENATED)),
+/// all constituents of all parts count, so the head is that of the outermost part.
+///
+/// ## Errors
+///
+/// Same as [`decompound`](crate::decompound).
+pub fn decompound_headed<'a>(
+    word: impl AsRef<str>,
+    is_valid_single_word: &impl Fn(&str) -> bool,
+    config: impl Into<DecompositionConfig<'a>>,
+) -> Result<Decomposition, DecompositionError> {
+    let config = config.into();
+    let constituents = decompose(word.as_ref(), is_valid_single_word, &config)?;
+
+    Ok(Decomposition {
+        metadata: vec![None; constituents.len()],
+        constituents,
+        headedness: config.headedness,
+    })
+}
+
+/// Like [`decompound_headed`], but with a `lexicon` returning metadata (like
+/// part-of-speech or gender) for valid words instead of just whether they are valid,
+/// and a `rule` over that metadata which each constituent has to satisfy in its
+/// [`Role`]. The lexicon is consulted once per distinct form.
+///
+/// The head is identified according to [`DecompositionConfig::with_headedness`]. For
+/// words split on hyphens (see
+/// [`DecompositionOptions::SPLIT_HYPHENATED`](crate::DecompositionOptions::SPLIT_HYPHENATED)),
+/// only the head of the outermost part is the compound's, and checked as
+/// [`Role::Head`]; the heads of all other parts are checked as modifiers. Lemmas are
+/// looked up in place of forms, if any (see
+/// [`DecompositionConfig::with_lemmatizer`]).
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use decompound::{decompound_with_metadata, DecompositionOptions, Role};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum Pos {
+///     Noun(&'static str),
+///     Verb,
+/// }
+///
+/// let lexicon = HashMap::from([
+///     ("Fahr", Pos::Verb),
+///     ("Rad", Pos::Noun("neuter")),
+///     ("Schloss", Pos::Noun("neuter")),
+///     ("Bahn", Pos::Noun("feminine")),
+/// ]);
+///
+/// // The head must be a noun; verb stems are allowed only as modifiers.
+/// let rule = |pos: &Pos, role| role == Role::Modifier || matches!(pos, Pos::Noun(_));
+///
+/// let decomposition = decompound_with_metadata(
+///     "Fahrradschloss",
+///     &|w| lexicon.get(w).copied(),
+///     &rule,
+///     DecompositionOptions::TRY_TITLECASE_SUFFIX,
+/// ).unwrap();
+///
+/// assert_eq!(
+///     decomposition.constituents().iter().map(|c| c.form()).collect::<Vec<_>>(),
+///     vec!["Fahr", "Rad", "Schloss"]
+/// );
+/// // The compound's gender is its head's.
+/// assert_eq!(decomposition.compound_metadata(), Some(&Pos::Noun("neuter")));
+/// ```
+///
+/// ## Errors
+///
+/// Same as [`decompound`](crate::decompound), where words are only valid if `rule`
+/// allows them as a head.
+pub fn decompound_with_metadata<'a, M: Clone>(
+    word: impl AsRef<str>,
+    lexicon: &impl Fn(&str) -> Option<M>,
+    rule: &impl Fn(&M, Role) -> bool,
+    config: impl Into<DecompositionConfig<'a>>,
+) -> Result<Decomposition<M>, DecompositionError> {
+    let cache: RefCell<HashMap<String, Option<M>>> = RefCell::new(HashMap::new());
+    let lookup = |w: &str| -> Option<M> {
+        if let Some(metadata) = cache.borrow().get(w) {
+            return metadata.clone();
+        }
+
+        let metadata = lexicon(w);
+        let _ = cache.borrow_mut().insert(w.to_owned(), metadata.clone());
+        metadata
+    };
+
+    let is_valid_single_word = |w: &str| lookup(w).is_some();
+    // Constituents valid by other means than the lexicon have no metadata to judge.
+    let role_filter = |form: &str, role| lookup(form).map_or(true, |m| rule(&m, role));
+
+    let mut config = config.into();
+    config.role_filter = Some(&role_filter);
+
+    let constituents = decompose(word.as_ref(), &is_valid_single_word, &config)?;
+    let metadata = constituents
+        .iter()
+        // Found valid as is, or only by its lemma.
+        .map(|c| lookup(c.form()).or_else(|| c.lemma().and_then(lookup)))
+        .collect();
+
+    Ok(Decomposition {
+        constituents,
+        metadata,
+        headedness: config.headedness,
+    })
+}
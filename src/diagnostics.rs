@@ -0,0 +1,276 @@
+//! Details on why a word could not be decomposed.
+
+use std::{error::Error, fmt, ops::Range};
+
+use crate::{
+    decompose,
+    hints::Hinted,
+    observer::{Candidate, Recorder},
+    DecompositionConfig, DecompositionError, DecompositionOptions,
+};
+
+/// Details on why decomposing a word failed, as part of a [`DiagnosedError`].
+///
+/// Gathered during the search, so they only cover what it got to explore: little if it
+/// ran out of budget (see [`DecompositionError::BudgetExhausted`]), and less with
+/// other strategies than [`SearchStrategy::Exhaustive`].
+///
+/// [`SearchStrategy::Exhaustive`]: crate::SearchStrategy::Exhaustive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostics {
+    longest_valid_prefix: Option<String>,
+    failing_segment: Option<(usize, String)>,
+    furthest_position: usize,
+    options: DecompositionOptions,
+}
+
+impl Diagnostics {
+    /// The longest valid constituent the failing part of the word starts with, short of
+    /// all of it. That part is the [`Diagnostics::failing_segment`] if any, otherwise
+    /// the whole word.
+    #[must_use]
+    pub fn longest_valid_prefix(&self) -> Option<&str> {
+        self.longest_valid_prefix.as_deref()
+    }
+
+    /// Index and text of the first hyphen-separated segment which is neither a compound
+    /// nor a single word, if split on hyphens (see
+    /// [`DecompositionOptions::SPLIT_HYPHENATED`]) at all.
+    #[must_use]
+    pub fn failing_segment(&self) -> Option<(usize, &str)> {
+        self.failing_segment
+            .as_ref()
+            .map(|(index, text)| (*index, text.as_str()))
+    }
+
+    /// Byte offset into the word up to which it could be covered by a sequence of
+    /// valid constituents, starting at the beginning of the failing part. If it is the
+    /// end of that part, the word did decompose, but was rejected for other reasons
+    /// (like [`DecompositionConfig::with_max_constituents`]).
+    #[must_use]
+    pub fn furthest_position(&self) -> usize {
+        self.furthest_position
+    }
+
+    /// The options in effect.
+    #[must_use]
+    pub fn options(&self) -> &DecompositionOptions {
+        &self.options
+    }
+}
+
+/// A [`DecompositionError`] along with [`Diagnostics`] on how it came about, as
+/// returned by [`decompound_with_diagnostics`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosedError {
+    error: DecompositionError,
+    diagnostics: Diagnostics,
+}
+
+impl DiagnosedError {
+    /// The error, exactly as returned by [`decompound`](crate::decompound).
+    #[must_use]
+    pub fn error(&self) -> &DecompositionError {
+        &self.error
+    }
+
+    /// Details on the error.
+    #[must_use]
+    pub fn diagnostics(&self) -> &Diagnostics {
+        &self.diagnostics
+    }
+
+    /// Drops the diagnostics.
+    #[must_use]
+    pub fn into_error(self) -> DecompositionError {
+        self.error
+    }
+}
+
+impl fmt::Display for DiagnosedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let diagnostics = &self.diagnostics;
+
+        write!(
+            f,
+            "{} (reached byte {}",
+            self.error, diagnostics.furthest_position
+        )?;
+        if let Some((index, text)) = &diagnostics.failing_segment {
+            write!(f, ", in segment {index} '{text}'")?;
+        }
+        if let Some(prefix) = &diagnostics.longest_valid_prefix {
+            write!(f, ", longest valid prefix '{prefix}'")?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl Error for DiagnosedError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<DiagnosedError> for DecompositionError {
+    fn from(error: DiagnosedError) -> Self {
+        error.error
+    }
+}
+
+/// Like [`decompound`](crate::decompound), but explains failures.
+///
+/// ```
+/// use decompound::{decompound_with_diagnostics, DecompositionError, DecompositionOptions};
+///
+/// let is_valid_single_word = |w: &str| ["bed", "room", "error"].contains(&w);
+///
+/// let error = decompound_with_diagnostics(
+///     "bedroom-errorless",
+///     &is_valid_single_word,
+///     DecompositionOptions::SPLIT_HYPHENATED,
+/// ).unwrap_err();
+///
+/// assert_eq!(error.error(), &DecompositionError::NothingValid);
+///
+/// let diagnostics = error.diagnostics();
+/// assert_eq!(diagnostics.failing_segment(), Some((1, "errorless")));
+/// assert_eq!(diagnostics.longest_valid_prefix(), Some("error"));
+/// assert_eq!(diagnostics.furthest_position(), "bedroom-error".len());
+/// ```
+///
+/// ## Errors
+///
+/// Same as [`decompound`](crate::decompound), along with [`Diagnostics`]. These are
+/// gathered for [`DecompositionError::SingleWord`] as well.
+pub fn decompound_with_diagnostics<'a>(
+    word: impl AsRef<str>,
+    is_valid_single_word: &impl Fn(&str) -> bool,
+    config: impl Into<DecompositionConfig<'a>>,
+) -> Result<Vec<String>, DiagnosedError> {
+    let word = word.as_ref();
+    let config = config.into();
+
+    let recorder = Recorder::new(config.observer);
+    let mut observed = config.clone();
+    observed.observer = Some(&recorder);
+
+    match decompose(word, is_valid_single_word, &observed) {
+        Ok(constituents) => Ok(constituents.into_iter().map(|c| c.form).collect()),
+        Err(error) => Err(DiagnosedError {
+            diagnostics: diagnose(word, &config, &error, &recorder.into_searches()),
+            error,
+        }),
+    }
+}
+
+/// Diagnoses `error`, judging by the candidates checked in each search (see
+/// [`Recorder`]) of the failed decomposition of `word`.
+fn diagnose(
+    word: &str,
+    config: &DecompositionConfig<'_>,
+    error: &DecompositionError,
+    searches: &[Vec<Candidate>],
+) -> Diagnostics {
+    let hinted = Hinted::new(word);
+    let strips_hints = config.strips_boundary_hints();
+    let stripped = if strips_hints {
+        hinted.stripped()
+    } else {
+        word
+    };
+    let restore = |span: Range<usize>| {
+        if strips_hints {
+            hinted.restore(&span)
+        } else {
+            span
+        }
+    };
+
+    let split_hyphenated = config
+        .options
+        .contains(DecompositionOptions::SPLIT_HYPHENATED);
+
+    // Where each search's word starts. Hyphen-separated parts are searched in order,
+    // until the first one failing.
+    let segments: Vec<&str> = if split_hyphenated {
+        stripped.split('-').collect()
+    } else {
+        vec![stripped]
+    };
+    let offsets: Vec<usize> = segments
+        .iter()
+        .scan(0, |offset, segment| {
+            let start = *offset;
+            *offset += segment.len() + '-'.len_utf8();
+            Some(start)
+        })
+        .collect();
+
+    // Valid candidates, by byte ranges in `stripped`.
+    let mut valid: Vec<(Range<usize>, &str)> = searches
+        .iter()
+        .zip(&offsets)
+        .flat_map(|(candidates, &offset)| {
+            candidates.iter().filter(|c| c.valid).map(move |c| {
+                (
+                    (c.span.start + offset)..(c.span.end + offset),
+                    c.form.as_str(),
+                )
+            })
+        })
+        .collect();
+    valid.sort_by_key(|(span, _)| span.start);
+
+    // The failing part of the word: the last part searched, unless it did not fail
+    // itself.
+    let failing = match searches.len().checked_sub(1) {
+        Some(index)
+            if segments.len() > 1 && !matches!(error, DecompositionError::SingleWord(_)) =>
+        {
+            Some(index)
+        }
+        _ => None,
+    };
+    let part = failing.map_or(0..stripped.len(), |index| {
+        offsets[index]..offsets[index] + segments[index].len()
+    });
+
+    // Valid constituents within the part, short of all of it.
+    let constituents = valid
+        .iter()
+        .filter(|(span, _)| span.start >= part.start && span.end <= part.end && *span != part);
+
+    let longest_valid_prefix = constituents
+        .clone()
+        .filter(|(span, _)| span.start == part.start)
+        // The first form found valid, of the longest prefix.
+        .fold(
+            None,
+            |longest: Option<&(Range<usize>, &str)>, prefix| match longest {
+                Some(longest) if longest.0.end >= prefix.0.end => Some(longest),
+                _ => Some(prefix),
+            },
+        )
+        .map(|(_, form)| (*form).to_owned());
+
+    let mut reachable = vec![part.start];
+    for (span, _) in constituents {
+        if reachable.contains(&span.start) && !reachable.contains(&span.end) {
+            reachable.push(span.end);
+
+            // Parts searched separately follow each other.
+            if split_hyphenated && stripped[span.end..].starts_with('-') {
+                reachable.push(span.end + '-'.len_utf8());
+            }
+        }
+    }
+    let furthest = reachable.into_iter().max().unwrap_or(part.start);
+
+    Diagnostics {
+        longest_valid_prefix,
+        failing_segment: failing.map(|index| (index, word[restore(part.clone())].to_owned())),
+        furthest_position: restore(furthest..furthest).start,
+        options: config.options.clone(),
+    }
+}
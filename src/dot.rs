@@ -0,0 +1,174 @@
+//! Graphviz export of the search space explored for a word.
+
+use std::{fmt::Write, ops::Range};
+
+use crate::{
+    decompose,
+    hints::Hinted,
+    observer::{Candidate, Recorder},
+    Constituent, DecompositionConfig, DecompositionError, DecompositionOptions,
+};
+
+/// Renders the search space [`decompound`](crate::decompound) explores for `word` as a
+/// [Graphviz](https://graphviz.org/) graph in DOT format, to see why a word was split
+/// the way it was (or not at all).
+///
+/// Nodes sit at the char boundaries of the word, numbered from zero (boundary hints,
+/// see [`DecompositionOptions::BOUNDARY_HINTS`], do not count). There is an edge for
+/// every candidate constituent checked for validity, labelled with its form:
+///
+/// - the decomposition returned is highlighted in bold blue,
+/// - valid constituents are black,
+/// - invalid ones gray, and dashed if they are transformed variants, like titlecased
+///   ones (see [`DecompositionOptions::TRY_TITLECASE_SUFFIX`]).
+///
+/// ```
+/// use decompound::{search_space_dot, DecompositionOptions};
+///
+/// let is_valid_single_word = |w: &str| ["Haus", "Boot"].contains(&w);
+///
+/// let dot = search_space_dot(
+///     "Hausboot",
+///     &is_valid_single_word,
+///     DecompositionOptions::TRY_TITLECASE_SUFFIX,
+/// );
+///
+/// assert!(dot.starts_with("digraph"));
+/// assert!(dot.contains(r#"0 -> 4 [label="Haus", color=blue, fontcolor=blue, penwidth=2];"#));
+/// assert!(dot.contains(r#"4 -> 8 [label="boot", color=gray, fontcolor=gray];"#));
+/// assert!(dot.contains(r#"4 -> 8 [label="Boot", color=blue, fontcolor=blue, penwidth=2];"#));
+/// ```
+///
+/// Render with, for example, `dot -Tsvg`.
+#[allow(clippy::missing_panics_doc)] // Candidates are spans of the word searched.
+pub fn search_space_dot<'a>(
+    word: impl AsRef<str>,
+    is_valid_single_word: &impl Fn(&str) -> bool,
+    config: impl Into<DecompositionConfig<'a>>,
+) -> String {
+    let word = word.as_ref();
+    let config = config.into();
+
+    let recorder = Recorder::new(config.observer);
+    let mut observed = config.clone();
+    observed.observer = Some(&recorder);
+
+    let accepted = match decompose(word, is_valid_single_word, &observed) {
+        Ok(constituents) => constituents,
+        Err(DecompositionError::SingleWord(form)) => vec![Constituent {
+            span: 0..word.len(),
+            form,
+            lemma: None,
+            lexicon: None,
+        }],
+        Err(_) => Vec::new(),
+    };
+
+    let hinted = Hinted::new(word);
+    let strips_hints = config.strips_boundary_hints();
+    let stripped = if strips_hints {
+        hinted.stripped()
+    } else {
+        word
+    };
+    let restore = |span: &Range<usize>| {
+        if strips_hints {
+            hinted.restore(span)
+        } else {
+            span.clone()
+        }
+    };
+
+    let candidates = distinct_candidates(stripped, &config, recorder.into_searches());
+
+    let boundaries: Vec<usize> = stripped
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(stripped.len()))
+        .collect();
+    let node = |offset: usize| {
+        boundaries
+            .binary_search(&offset)
+            .expect("Candidate must span char boundaries")
+    };
+
+    let mut dot = String::new();
+    let _ = writeln!(dot, "digraph decomposition {{");
+    let _ = writeln!(dot, "    label=\"{}\";", escape(word));
+    let _ = writeln!(dot, "    rankdir=LR;");
+    let _ = writeln!(dot, "    node [shape=circle];");
+
+    for i in 0..boundaries.len() {
+        let _ = writeln!(dot, "    {i};");
+    }
+
+    for candidate in candidates {
+        let span = restore(&candidate.span);
+        let is_accepted = accepted
+            .iter()
+            .any(|c| c.span == span && c.form == candidate.form);
+
+        let mut attributes = format!("label=\"{}\"", escape(&candidate.form));
+        if is_accepted {
+            attributes.push_str(", color=blue, fontcolor=blue, penwidth=2");
+        } else if !candidate.valid {
+            attributes.push_str(", color=gray, fontcolor=gray");
+
+            if candidate.transformed {
+                attributes.push_str(", style=dashed");
+            }
+        }
+
+        let _ = writeln!(
+            dot,
+            "    {} -> {} [{attributes}];",
+            node(candidate.span.start),
+            node(candidate.span.end)
+        );
+    }
+
+    let _ = writeln!(dot, "}}");
+    dot
+}
+
+/// All distinct candidates of `searches` (see [`Recorder`]) of `word`, free of boundary
+/// hints, in order of first check. Spans are relative to `word`.
+fn distinct_candidates(
+    word: &str,
+    config: &DecompositionConfig<'_>,
+    searches: Vec<Vec<Candidate>>,
+) -> Vec<Candidate> {
+    // Hyphen-separated parts are searched in order.
+    let segments: Vec<&str> = if config
+        .options
+        .contains(DecompositionOptions::SPLIT_HYPHENATED)
+    {
+        word.split('-').collect()
+    } else {
+        vec![word]
+    };
+
+    let mut candidates: Vec<Candidate> = Vec::new();
+    let mut offset = 0;
+    for (segment, searched) in segments.into_iter().zip(searches) {
+        for mut candidate in searched {
+            candidate.span = (candidate.span.start + offset)..(candidate.span.end + offset);
+
+            if !candidates
+                .iter()
+                .any(|c| c.span == candidate.span && c.form == candidate.form)
+            {
+                candidates.push(candidate);
+            }
+        }
+
+        offset += segment.len() + '-'.len_utf8();
+    }
+
+    candidates
+}
+
+/// Escapes `text` for use in a quoted DOT string.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
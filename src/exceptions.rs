@@ -0,0 +1,105 @@
+//! Curated exceptions to what the search would find on its own.
+
+use std::collections::{HashMap, HashSet};
+
+/// Exceptions to the regular search, for words it is known to get wrong.
+///
+/// When passed to [`DecompositionConfig::with_exceptions`], these are consulted before
+/// searching any word, hyphen-separated part of a word or suffix during the search.
+/// All entries are matched exactly, against the form a constituent is looked up as (so
+/// for example titlecased, see
+/// [`DecompositionOptions::TRY_TITLECASE_SUFFIX`](crate::DecompositionOptions::TRY_TITLECASE_SUFFIX)).
+///
+/// ```
+/// use decompound::{decompound, DecompositionConfig, DecompositionOptions, Exceptions};
+///
+/// // "Ur" is a prefix, not a word, so missing here...
+/// let is_valid_single_word = |w: &str| ["Urin", "Instinkt", "stinkt"].contains(&w);
+///
+/// // Without exceptions
+/// assert_eq!(
+///     decompound(
+///         "Urinstinkt",
+///         &is_valid_single_word,
+///         DecompositionOptions::TRY_TITLECASE_SUFFIX,
+///     ).unwrap(),
+///     vec!["Urin", "stinkt"]
+/// );
+///
+/// // With exceptions, which are not subject to the validity check
+/// let exceptions = Exceptions::new().with_fixed_split("Urinstinkt", ["Ur", "Instinkt"]);
+/// assert_eq!(
+///     decompound(
+///         "Urinstinkt",
+///         &is_valid_single_word,
+///         DecompositionConfig::new(DecompositionOptions::TRY_TITLECASE_SUFFIX)
+///             .with_exceptions(&exceptions),
+///     ).unwrap(),
+///     vec!["Ur", "Instinkt"]
+/// );
+/// ```
+///
+/// [`DecompositionConfig::with_exceptions`]: crate::DecompositionConfig::with_exceptions
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Exceptions {
+    denied: HashSet<String>,
+    undecomposable: HashSet<String>,
+    fixed: HashMap<String, Vec<String>>,
+}
+
+impl Exceptions {
+    /// Creates an empty set of exceptions.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Never use `constituent` as a constituent, even if the validity check accepts it.
+    /// That includes words consisting of nothing but `constituent`.
+    #[must_use]
+    pub fn with_denied_constituent(mut self, constituent: impl Into<String>) -> Self {
+        let _ = self.denied.insert(constituent.into());
+        self
+    }
+
+    /// Never decompose `word`, wherever it occurs: on its own, it is a single word, as
+    /// a suffix, it is a single constituent.
+    #[must_use]
+    pub fn with_undecomposable_word(mut self, word: impl Into<String>) -> Self {
+        let _ = self.undecomposable.insert(word.into());
+        self
+    }
+
+    /// Always decompose `word` into `parts`, without searching at all, wherever it
+    /// occurs. `parts` are returned as given, but there have to be at least two of them,
+    /// spelling out `word` char for char (casing aside), or the entry is ignored.
+    #[must_use]
+    pub fn with_fixed_split(
+        mut self,
+        word: impl Into<String>,
+        parts: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        let _ = self
+            .fixed
+            .insert(word.into(), parts.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Whether there are no exceptions at all.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.denied.is_empty() && self.undecomposable.is_empty() && self.fixed.is_empty()
+    }
+
+    pub(crate) fn is_denied(&self, form: &str) -> bool {
+        self.denied.contains(form)
+    }
+
+    pub(crate) fn is_undecomposable(&self, form: &str) -> bool {
+        self.undecomposable.contains(form)
+    }
+
+    pub(crate) fn fixed_split(&self, form: &str) -> Option<&[String]> {
+        self.fixed.get(form).map(Vec::as_slice)
+    }
+}
@@ -0,0 +1,128 @@
+//! Corpus frequencies of words, for ranking decompositions.
+
+use std::{collections::HashMap, fs, io, path::Path};
+
+/// Corpus frequencies of words, as used to rank decompositions following [Koehn and
+/// Knight (2003)](https://aclanthology.org/E03-1076/): when passed to
+/// [`DecompositionConfig::with_frequencies`], the decomposition whose constituents'
+/// frequencies have the highest geometric mean wins, unless the word is valid as a
+/// single word and at least as frequent itself, in which case it is not decomposed.
+///
+/// Frequencies are read in the common plain text format of one `word<TAB>count` per
+/// line.
+///
+/// ```
+/// use decompound::{decompound, DecompositionConfig, DecompositionOptions, FrequencyLexicon};
+///
+/// let frequencies = FrequencyLexicon::parse("Baum\t5000\nAst\t1200\nBau\t3000\nMast\t200");
+/// let is_valid_single_word = |w: &str| frequencies.contains(w);
+///
+/// // Without frequencies: ambiguous, first valid split wins
+/// assert_eq!(
+///     decompound(
+///         "Baumast",
+///         &is_valid_single_word,
+///         DecompositionOptions::TRY_TITLECASE_SUFFIX,
+///     ).unwrap(),
+///     vec!["Bau", "Mast"]
+/// );
+///
+/// // With frequencies
+/// assert_eq!(
+///     decompound(
+///         "Baumast",
+///         &is_valid_single_word,
+///         DecompositionConfig::new(DecompositionOptions::TRY_TITLECASE_SUFFIX)
+///             .with_frequencies(&frequencies),
+///     ).unwrap(),
+///     vec!["Baum", "Ast"]
+/// );
+/// ```
+///
+/// [`DecompositionConfig::with_frequencies`]: crate::DecompositionConfig::with_frequencies
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FrequencyLexicon {
+    counts: HashMap<String, u64>,
+}
+
+impl FrequencyLexicon {
+    /// Parses `frequencies`, one `word<TAB>count` per line. Counts of words listed more
+    /// than once are added up. Lines not of that form, like empty ones, are ignored.
+    #[must_use]
+    pub fn parse(frequencies: &str) -> Self {
+        frequencies
+            .lines()
+            .filter_map(|line| {
+                let (word, count) = line.split_once('\t')?;
+                let count = count.trim().parse().ok()?;
+
+                Some((word.to_owned(), count))
+            })
+            .collect()
+    }
+
+    /// Reads frequencies from the file at `path`, see [`FrequencyLexicon::parse`].
+    ///
+    /// ## Errors
+    ///
+    /// If the file cannot be read.
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::parse(&fs::read_to_string(path)?))
+    }
+
+    /// Number of words.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Whether there are no words at all.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Whether `word` is listed, even if with a count of zero. Handy as a validity
+    /// check.
+    #[must_use]
+    pub fn contains(&self, word: &str) -> bool {
+        self.counts.contains_key(word)
+    }
+
+    /// How often `word` occurred, zero if not listed.
+    #[must_use]
+    pub fn frequency(&self, word: &str) -> u64 {
+        self.counts.get(word).copied().unwrap_or_default()
+    }
+
+    /// Geometric mean of the frequencies of all `constituents`. Zero if any of them
+    /// is not listed, or there are none.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // Counts beyond 2^52 are not a concern.
+    pub fn geometric_mean(&self, constituents: &[&str]) -> f64 {
+        if constituents.is_empty() {
+            return 0.0;
+        }
+
+        // In log space, as the product of frequencies easily overflows.
+        let log_sum: f64 = constituents
+            .iter()
+            .map(|c| (self.frequency(c) as f64).ln())
+            .sum();
+
+        (log_sum / constituents.len() as f64).exp()
+    }
+}
+
+impl<S: Into<String>> FromIterator<(S, u64)> for FrequencyLexicon {
+    fn from_iter<I: IntoIterator<Item = (S, u64)>>(iter: I) -> Self {
+        let mut counts = HashMap::new();
+
+        for (word, count) in iter {
+            let total: &mut u64 = counts.entry(word.into()).or_default();
+            *total = total.saturating_add(count);
+        }
+
+        Self { counts }
+    }
+}
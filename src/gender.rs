@@ -0,0 +1,103 @@
+//! Gender-inclusive forms, like `Lehrer*innen`.
+
+/// Feminine suffixes following a [`GenderMarker`], in their lowercase form.
+const FEMININE_SUFFIXES: &[&str] = &["innen", "in"];
+
+/// Marker separating the base of a gender-inclusive form from its feminine suffix.
+///
+/// See [`DecompositionOptions::GENDER_INCLUSIVE`](crate::DecompositionOptions::GENDER_INCLUSIVE)
+/// for how these are recognized during decomposition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GenderMarker {
+    /// *Gendersternchen*, as in `Lehrer*innen`.
+    Asterisk,
+    /// *Gender-Doppelpunkt*, as in `Lehrer:innen`.
+    Colon,
+    /// *Gender-Gap*, as in `Lehrer_innen`.
+    Underscore,
+    /// *Binnen-I*, as in `LehrerInnen`. Unlike the others, this marker does not occupy
+    /// a character of its own, but is the capitalized start of the feminine suffix.
+    BinnenI,
+}
+
+impl GenderMarker {
+    /// Splits a gender-inclusive form into its base, marker and feminine suffix. The
+    /// suffix is returned as found, so capitalized for [`GenderMarker::BinnenI`].
+    ///
+    /// ```
+    /// use decompound::GenderMarker;
+    ///
+    /// assert_eq!(
+    ///     GenderMarker::split("Lehrer*innen"),
+    ///     Some(("Lehrer", GenderMarker::Asterisk, "innen"))
+    /// );
+    /// assert_eq!(
+    ///     GenderMarker::split("LehrerIn"),
+    ///     Some(("Lehrer", GenderMarker::BinnenI, "In"))
+    /// );
+    /// assert_eq!(GenderMarker::split("Lehrerinnen"), None);
+    /// ```
+    #[must_use]
+    pub fn split(word: &str) -> Option<(&str, Self, &str)> {
+        FEMININE_SUFFIXES
+            .iter()
+            .find_map(|suffix| {
+                let rest = word.strip_suffix(suffix)?;
+                let (base, marker) = match rest.chars().next_back()? {
+                    '*' => (&rest[..rest.len() - 1], GenderMarker::Asterisk),
+                    ':' => (&rest[..rest.len() - 1], GenderMarker::Colon),
+                    '_' => (&rest[..rest.len() - 1], GenderMarker::Underscore),
+                    _ => return None,
+                };
+
+                (!base.is_empty()).then_some((base, marker, &word[rest.len()..]))
+            })
+            .or_else(|| {
+                FEMININE_SUFFIXES.iter().find_map(|suffix| {
+                    let capitalized = format!("I{}", &suffix[1..]);
+                    let base = word.strip_suffix(&capitalized)?;
+
+                    base.chars()
+                        .next_back()
+                        .filter(|c| c.is_lowercase())
+                        .map(|_| (base, GenderMarker::BinnenI, &word[base.len()..]))
+                })
+            })
+    }
+
+    /// The character this marker is written as, if it occupies one of its own.
+    #[must_use]
+    pub fn as_char(self) -> Option<char> {
+        match self {
+            GenderMarker::Asterisk => Some('*'),
+            GenderMarker::Colon => Some(':'),
+            GenderMarker::Underscore => Some('_'),
+            GenderMarker::BinnenI => None,
+        }
+    }
+}
+
+/// Checks whether `word` is a gender-inclusive form whose base *and* feminine form
+/// (`Lehrer` and `Lehrerinnen` for `Lehrer*innen`) are both valid.
+pub(crate) fn is_valid(word: &str, is_valid_single_word: &impl Fn(&str) -> bool) -> bool {
+    match GenderMarker::split(word) {
+        Some((base, _, suffix)) => {
+            is_valid_single_word(base)
+                && is_valid_single_word(&format!("{base}{}", suffix.to_lowercase()))
+        }
+        None => false,
+    }
+}
+
+/// Checks whether splitting between `left` and `right` would tear a gender-inclusive
+/// form apart, for example `Lehrer` and `Innen` for `LehrerInnen`.
+pub(crate) fn cuts_marker(left: &str, right: &str) -> bool {
+    let is_marker = |c: char| matches!(c, '*' | ':' | '_');
+
+    match (left.chars().next_back(), right.chars().next()) {
+        (_, Some(c)) if is_marker(c) => right[c.len_utf8()..].starts_with("in"),
+        (Some(c), _) if is_marker(c) => right.starts_with("in"),
+        (Some(c), Some('I')) if c.is_lowercase() => right.starts_with("In"),
+        _ => false,
+    }
+}
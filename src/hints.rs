@@ -0,0 +1,74 @@
+//! Invisible characters hinting at morpheme boundaries, like soft hyphens.
+
+use std::ops::Range;
+
+/// Characters marking a (potential) boundary, without being part of any constituent.
+pub(crate) const BOUNDARY_HINTS: &[char] = &[
+    '\u{00AD}', // SOFT HYPHEN
+    '\u{200C}', // ZERO WIDTH NON-JOINER
+];
+
+/// A word with all [`BOUNDARY_HINTS`] stripped, remembering where they were.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Hinted {
+    stripped: String,
+    /// Byte offsets into the *stripped* word at which hints were found.
+    hints: Vec<usize>,
+    /// For each char boundary in the stripped word, the original byte offset of the
+    /// char starting there.
+    starts: Vec<usize>,
+    /// For each char boundary in the stripped word, the original byte offset right
+    /// after the char ending there.
+    ends: Vec<usize>,
+}
+
+impl Hinted {
+    pub(crate) fn new(word: &str) -> Self {
+        let mut stripped = String::with_capacity(word.len());
+        let mut hints = Vec::new();
+        let mut starts = vec![0; word.len() + 1];
+        let mut ends = vec![0; word.len() + 1];
+
+        for (i, c) in word.char_indices() {
+            if BOUNDARY_HINTS.contains(&c) {
+                if hints.last() != Some(&stripped.len()) {
+                    hints.push(stripped.len());
+                }
+                continue;
+            }
+
+            starts[stripped.len()] = i;
+            stripped.push(c);
+            ends[stripped.len()] = i + c.len_utf8();
+        }
+
+        starts[stripped.len()] = word.len();
+        starts.truncate(stripped.len() + 1);
+        ends.truncate(stripped.len() + 1);
+
+        Self {
+            stripped,
+            hints,
+            starts,
+            ends,
+        }
+    }
+
+    pub(crate) fn stripped(&self) -> &str {
+        &self.stripped
+    }
+
+    pub(crate) fn hints(&self) -> &[usize] {
+        &self.hints
+    }
+
+    /// Maps a span in the stripped word back onto the original one. Hints at the
+    /// span's edges are *not* included.
+    pub(crate) fn restore(&self, span: &Range<usize>) -> Range<usize> {
+        if span.is_empty() {
+            return self.starts[span.start]..self.starts[span.start];
+        }
+
+        self.starts[span.start]..self.ends[span.end]
+    }
+}
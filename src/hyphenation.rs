@@ -0,0 +1,154 @@
+//! Liang's hyphenation algorithm, as known from TeX.
+
+use std::{collections::HashMap, fs, io, path::Path};
+
+/// A set of hyphenation patterns for Liang's algorithm, as used by TeX and many other
+/// hyphenators.
+///
+/// When passed to
+/// [`DecompositionConfig::with_hyphenation_patterns`](crate::DecompositionConfig::with_hyphenation_patterns),
+/// words are only ever split where these patterns allow hyphenation. That prevents
+/// absurd splits *within* syllables, and speeds up the search, as fewer split points
+/// are tried.
+///
+/// Patterns are read in the common plain text format, as for example distributed as
+/// `hyph-de-1996.pat.txt` by the [`hyph-utf8`](https://ctan.org/pkg/hyph-utf8) project:
+/// whitespace-separated patterns of lowercase letters, with digits between them, and
+/// `.` marking word edges. Odd digits allow hyphenation at their position, even ones
+/// forbid it, and the highest digit wins.
+///
+/// ```
+/// use decompound::HyphenationPatterns;
+///
+/// // Far from a complete set of patterns; load real ones from disk.
+/// let patterns = HyphenationPatterns::parse("u1c r1z g1n");
+///
+/// let word = "Druckerzeugnis";
+/// let points = patterns.hyphenation_points(word);
+///
+/// assert_eq!(
+///     points.iter().map(|&i| &word[..i]).collect::<Vec<_>>(),
+///     vec!["Dru", "Drucker", "Druckerzeug"]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct HyphenationPatterns {
+    /// Maps the letters of a pattern to its values, one more than there are letters.
+    patterns: HashMap<String, Vec<u8>>,
+    /// Length in chars of the longest pattern (including dots).
+    longest: usize,
+    left_min: usize,
+    right_min: usize,
+}
+
+impl HyphenationPatterns {
+    /// Parses `patterns`. Comments (from `%` to the end of the line) are ignored, as are
+    /// TeX commands (like `\patterns{`) and braces, so patterns can be read straight
+    /// from TeX sources.
+    ///
+    /// Words are not hyphenated within the first or last two chars. Use
+    /// [`HyphenationPatterns::with_min_lengths`] to change that.
+    #[must_use]
+    pub fn parse(patterns: &str) -> Self {
+        let mut parsed = HashMap::new();
+        let mut longest = 0;
+
+        let tokens = patterns
+            .lines()
+            .map(|line| line.split('%').next().unwrap_or_default())
+            .flat_map(str::split_whitespace)
+            .filter(|token| !token.starts_with('\\') && !token.contains(['{', '}']));
+
+        for token in tokens {
+            let mut letters = String::with_capacity(token.len());
+            let mut values = vec![0];
+
+            for c in token.chars() {
+                if let Some(digit) = c.to_digit(10).and_then(|d| u8::try_from(d).ok()) {
+                    if let Some(value) = values.last_mut() {
+                        *value = digit;
+                    }
+                } else {
+                    letters.push(c.to_lowercase().next().unwrap_or(c));
+                    values.push(0);
+                }
+            }
+
+            if letters.is_empty() {
+                continue;
+            }
+
+            longest = longest.max(letters.chars().count());
+            let _ = parsed.insert(letters, values);
+        }
+
+        Self {
+            patterns: parsed,
+            longest,
+            left_min: 2,
+            right_min: 2,
+        }
+    }
+
+    /// Reads patterns from the file at `path`, see [`HyphenationPatterns::parse`].
+    ///
+    /// ## Errors
+    ///
+    /// If the file cannot be read.
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::parse(&fs::read_to_string(path)?))
+    }
+
+    /// Sets the minimum number of chars before the first and after the last
+    /// hyphenation point of a word.
+    #[must_use]
+    pub fn with_min_lengths(mut self, left: usize, right: usize) -> Self {
+        self.left_min = left;
+        self.right_min = right;
+        self
+    }
+
+    /// Number of patterns.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.patterns.len()
+    }
+
+    /// Whether there are no patterns at all.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// Byte offsets into `word` at which it may be hyphenated, in ascending order.
+    #[must_use]
+    pub fn hyphenation_points(&self, word: &str) -> Vec<usize> {
+        let offsets: Vec<usize> = word.char_indices().map(|(i, _)| i).collect();
+        let chars: Vec<char> = std::iter::once('.')
+            .chain(word.chars().map(|c| c.to_lowercase().next().unwrap_or(c)))
+            .chain(std::iter::once('.'))
+            .collect();
+
+        // `values[i]` is the value *before* `chars[i]`.
+        let mut values = vec![0; chars.len() + 1];
+        for start in 0..chars.len() {
+            let mut key = String::new();
+
+            for (end, c) in chars.iter().enumerate().skip(start).take(self.longest) {
+                key.push(*c);
+
+                if let Some(pattern) = self.patterns.get(&key) {
+                    for (value, at) in pattern.iter().zip(start..=end + 1) {
+                        values[at] = values[at].max(*value);
+                    }
+                }
+            }
+        }
+
+        // Position `i` in `word` (in chars) is preceded by the leading dot.
+        (self.left_min.max(1)..=offsets.len().saturating_sub(self.right_min.max(1)))
+            .filter(|&i| values[i + 1] % 2 == 1)
+            .map(|i| offsets[i])
+            .collect()
+    }
+}
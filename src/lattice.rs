@@ -0,0 +1,266 @@
+//! The raw search space of a word, for decoders of one's own.
+
+use std::{collections::HashMap, ops::Range};
+
+use crate::{hints::Hinted, is_valid_constituent, transform, DecompositionConfig, Position};
+
+/// A directed acyclic graph over the char boundaries of a word, with an edge for every
+/// valid constituent: the search space [`decompound`](crate::decompound) picks a
+/// decomposition from, for scoring paths through it by other means (like language
+/// models).
+///
+/// Nodes are numbered `0..nodes()`, in order of the char boundaries they sit at, so any
+/// path leads from lower to higher numbers. Every path from the first to the last node
+/// is a reading of the word, including the single edge spanning all of it, if the word
+/// is valid as a single word.
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use decompound::{DecompositionOptions, Lattice};
+///
+/// let frequencies: HashMap<&str, f64> = HashMap::from([
+///     ("Wach", 10.0),
+///     ("Stube", 50.0),
+///     ("Wachs", 100.0),
+///     ("Tube", 80.0),
+/// ]);
+/// let is_valid_single_word = |w: &str| frequencies.contains_key(w);
+///
+/// let lattice = Lattice::new(
+///     "Wachstube",
+///     &is_valid_single_word,
+///     DecompositionOptions::TRY_TITLECASE_SUFFIX,
+/// );
+///
+/// assert_eq!(lattice.count_paths(), 2);
+///
+/// // Unigram language model
+/// let (_, path) = lattice.best_path(|edge| -frequencies[edge.form()].ln()).unwrap();
+/// assert_eq!(
+///     path.iter().map(|edge| edge.form()).collect::<Vec<_>>(),
+///     vec!["Wachs", "Tube"]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lattice {
+    nodes: usize,
+    edges: Vec<LatticeEdge>,
+}
+
+/// A valid constituent in a [`Lattice`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LatticeEdge {
+    from: usize,
+    to: usize,
+    span: Range<usize>,
+    form: String,
+    transformed: bool,
+}
+
+impl LatticeEdge {
+    /// The node this edge starts at.
+    #[must_use]
+    pub fn from(&self) -> usize {
+        self.from
+    }
+
+    /// The node this edge ends at.
+    #[must_use]
+    pub fn to(&self) -> usize {
+        self.to
+    }
+
+    /// Byte range of the constituent in the word originally passed in, as for
+    /// [`Constituent::span`](crate::Constituent::span).
+    #[must_use]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// The form the constituent was found valid as.
+    #[must_use]
+    pub fn form(&self) -> &str {
+        &self.form
+    }
+
+    /// Whether the form differs from the word's slice, for example as it was
+    /// titlecased (see [`DecompositionConfig::with_transform`]).
+    #[must_use]
+    pub fn is_transformed(&self) -> bool {
+        self.transformed
+    }
+}
+
+impl Lattice {
+    /// Builds the lattice of `word`, calling `is_valid_single_word` once per distinct
+    /// form of each substring.
+    ///
+    /// Of `config`, only what concerns the validity of single constituents applies:
+    /// [`DecompositionOptions::TRY_TITLECASE_SUFFIX`](crate::DecompositionOptions::TRY_TITLECASE_SUFFIX)
+    /// and [`DecompositionConfig::with_transform`],
+    /// [`DecompositionOptions::GENDER_INCLUSIVE`](crate::DecompositionOptions::GENDER_INCLUSIVE),
+    /// [`DecompositionOptions::BOUNDARY_HINTS`](crate::DecompositionOptions::BOUNDARY_HINTS)
+    /// (hints are skipped, and nodes only sit at boundaries of the word without them),
+    /// [`DecompositionConfig::with_min_constituent_len`] and denied constituents of
+    /// [`DecompositionConfig::with_exceptions`]. Restrictions on where to split do not.
+    pub fn new<'a>(
+        word: impl AsRef<str>,
+        is_valid_single_word: &impl Fn(&str) -> bool,
+        config: impl Into<DecompositionConfig<'a>>,
+    ) -> Self {
+        let word = word.as_ref();
+        let config = config.into();
+
+        let hinted = Hinted::new(word);
+        let strips_hints = config.strips_boundary_hints();
+        let stripped = if strips_hints {
+            hinted.stripped()
+        } else {
+            word
+        };
+        let restore = |span: Range<usize>| {
+            if strips_hints {
+                hinted.restore(&span)
+            } else {
+                span
+            }
+        };
+
+        let boundaries: Vec<usize> = stripped
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(stripped.len()))
+            .collect();
+        let nodes = boundaries.len();
+
+        let mut validity: HashMap<String, bool> = HashMap::new();
+        let mut is_valid = |form: &str| {
+            if let Some(&valid) = validity.get(form) {
+                return valid;
+            }
+
+            let valid = is_valid_constituent(form, is_valid_single_word, &config);
+            let _ = validity.insert(form.to_owned(), valid);
+            valid
+        };
+
+        let mut edges = Vec::new();
+        for from in 0..nodes {
+            for to in (from + 1)..nodes {
+                if to - from < config.min_constituent_len {
+                    continue;
+                }
+
+                let (start, end) = (boundaries[from], boundaries[to]);
+                let surface = &stripped[start..end];
+
+                let position = Position::of(&(start..end), stripped.len());
+
+                for form in transform::forms(surface, position, &config) {
+                    if is_valid(&form) {
+                        edges.push(LatticeEdge {
+                            from,
+                            to,
+                            span: restore(start..end),
+                            transformed: form != surface,
+                            form,
+                        });
+                    }
+                }
+            }
+        }
+
+        Self { nodes, edges }
+    }
+
+    /// Number of nodes, one more than the number of chars in the word.
+    #[must_use]
+    pub fn nodes(&self) -> usize {
+        self.nodes
+    }
+
+    /// All edges, ordered by the node they start at, then the one they end at.
+    #[must_use]
+    pub fn edges(&self) -> &[LatticeEdge] {
+        &self.edges
+    }
+
+    /// All edges starting at `node`.
+    pub fn edges_from(&self, node: usize) -> impl Iterator<Item = &LatticeEdge> {
+        self.edges.iter().filter(move |edge| edge.from == node)
+    }
+
+    /// Number of paths from the first to the last node, saturating at
+    /// [`usize::MAX`].
+    #[must_use]
+    pub fn count_paths(&self) -> usize {
+        let mut counts = vec![0_usize; self.nodes];
+        counts[0] = 1;
+
+        for edge in &self.edges {
+            counts[edge.to] = counts[edge.to].saturating_add(counts[edge.from]);
+        }
+
+        counts[self.nodes - 1]
+    }
+
+    /// All paths from the first to the last node. There are exponentially many in the
+    /// worst case, see [`Lattice::count_paths`].
+    #[must_use]
+    pub fn paths(&self) -> Vec<Vec<&LatticeEdge>> {
+        let last = self.nodes - 1;
+
+        // All paths to each node, built front to back.
+        let mut paths: Vec<Vec<Vec<&LatticeEdge>>> = vec![Vec::new(); self.nodes];
+        paths[0].push(Vec::new());
+
+        for edge in &self.edges {
+            let extended: Vec<Vec<&LatticeEdge>> = paths[edge.from]
+                .iter()
+                .map(|path| {
+                    let mut path = path.clone();
+                    path.push(edge);
+                    path
+                })
+                .collect();
+
+            paths[edge.to].extend(extended);
+        }
+
+        std::mem::take(&mut paths[last])
+    }
+
+    /// The path from the first to the last node with the lowest total cost of its
+    /// edges, along with that cost. Of equally costly paths, the first one found wins.
+    /// `None` if there is no path.
+    pub fn best_path(
+        &self,
+        cost: impl Fn(&LatticeEdge) -> f64,
+    ) -> Option<(f64, Vec<&LatticeEdge>)> {
+        // Cheapest way to reach each node, by its total cost and the edge leading there.
+        let mut best: Vec<Option<(f64, Option<&LatticeEdge>)>> = vec![None; self.nodes];
+        best[0] = Some((0.0, None));
+
+        for edge in &self.edges {
+            if let Some((total, _)) = best[edge.from] {
+                let total = total + cost(edge);
+
+                if best[edge.to].map_or(true, |(current, _)| total < current) {
+                    best[edge.to] = Some((total, Some(edge)));
+                }
+            }
+        }
+
+        let (total, mut edge) = best[self.nodes - 1]?;
+
+        let mut path = Vec::new();
+        while let Some(current) = edge {
+            path.push(current);
+            edge = best[current.from].and_then(|(_, edge)| edge);
+        }
+        path.reverse();
+
+        Some((total, path))
+    }
+}
@@ -0,0 +1,177 @@
+//! Several named lexicons, to tell where constituents came from.
+
+use std::{cell::RefCell, collections::HashMap, fmt};
+
+use crate::{decompose, Constituent, DecompositionConfig, DecompositionError};
+
+/// An ordered set of named lexicons, like a general dictionary, a list of domain terms
+/// and one of loanwords, for [`decompound_with_lexicons`].
+///
+/// Lexicons are consulted in order of their priority, highest first, and in the order
+/// they were added for equal priorities. A word is attributed to the first lexicon
+/// containing it.
+///
+/// ```
+/// use decompound::Lexicons;
+///
+/// let general = |w: &str| ["Baum", "Ast", "Raum"].contains(&w);
+/// let forestry = |w: &str| ["Baum", "Ast", "Harvester"].contains(&w);
+///
+/// let lexicons = Lexicons::new()
+///     .with_lexicon("general", 0, &general)
+///     .with_lexicon("forestry", 1, &forestry);
+///
+/// assert_eq!(lexicons.source("Baum"), Some("forestry"));
+/// assert_eq!(lexicons.source("Raum"), Some("general"));
+/// assert_eq!(lexicons.source("Meeting"), None);
+/// ```
+#[derive(Default)]
+pub struct Lexicons<'a> {
+    /// Sorted by priority, highest first.
+    lexicons: Vec<Lexicon<'a>>,
+}
+
+struct Lexicon<'a> {
+    name: String,
+    priority: i32,
+    is_valid_single_word: &'a dyn Fn(&str) -> bool,
+}
+
+impl<'a> Lexicons<'a> {
+    /// Creates an empty set of lexicons, containing no words at all.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a lexicon named `name`, containing all words `is_valid_single_word` holds
+    /// for. Among decompositions ranking equally otherwise, the one whose constituents'
+    /// lexicons have the highest total `priority` wins.
+    #[must_use]
+    pub fn with_lexicon(
+        mut self,
+        name: impl Into<String>,
+        priority: i32,
+        is_valid_single_word: &'a dyn Fn(&str) -> bool,
+    ) -> Self {
+        // After all of at least equal priority, so earlier ones are consulted first.
+        let index = self
+            .lexicons
+            .iter()
+            .position(|lexicon| lexicon.priority < priority)
+            .unwrap_or(self.lexicons.len());
+
+        self.lexicons.insert(
+            index,
+            Lexicon {
+                name: name.into(),
+                priority,
+                is_valid_single_word,
+            },
+        );
+        self
+    }
+
+    /// The name of the lexicon `word` is attributed to, if any contains it.
+    #[must_use]
+    pub fn source(&self, word: &str) -> Option<&str> {
+        self.find(word)
+            .map(|index| self.lexicons[index].name.as_str())
+    }
+
+    /// Index of the lexicon `word` is attributed to, if any contains it.
+    fn find(&self, word: &str) -> Option<usize> {
+        self.lexicons
+            .iter()
+            .position(|lexicon| (lexicon.is_valid_single_word)(word))
+    }
+}
+
+impl fmt::Debug for Lexicons<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.lexicons
+                    .iter()
+                    .map(|lexicon| (&lexicon.name, lexicon.priority)),
+            )
+            .finish()
+    }
+}
+
+/// Like [`decompound_spans`](crate::decompound_spans), but with words valid if any of
+/// `lexicons` contains them, and each constituent attributed to its lexicon (see
+/// [`Constituent::lexicon`]). Each lexicon is consulted at most once per distinct
+/// form.
+///
+/// Lexicon priorities break ties between decompositions ranking equally otherwise, for
+/// example ones with the same number of constituents. They do not apply if a scorer is
+/// set (see [`DecompositionConfig::with_scorer`]), and do not count for
+/// [`decompound_with_ambiguity`](crate::decompound_with_ambiguity).
+///
+/// ```
+/// use decompound::{decompound_with_lexicons, DecompositionOptions, Lexicons};
+///
+/// let general = |w: &str| ["Bau", "Mast", "Baum", "Ast", "Raum"].contains(&w);
+/// let forestry = |w: &str| ["Baum", "Ast"].contains(&w);
+/// let loanwords = |w: &str| ["Meeting"].contains(&w);
+///
+/// let lexicons = Lexicons::new()
+///     .with_lexicon("general", 0, &general)
+///     .with_lexicon("forestry", 1, &forestry)
+///     .with_lexicon("loanwords", 0, &loanwords);
+///
+/// let sources = |word| {
+///     decompound_with_lexicons(word, &lexicons, DecompositionOptions::TRY_TITLECASE_SUFFIX)
+///         .unwrap()
+///         .iter()
+///         .map(|c| (c.form().to_owned(), c.lexicon().unwrap().to_owned()))
+///         .collect::<Vec<_>>()
+/// };
+///
+/// assert_eq!(
+///     sources("Meetingraum"),
+///     vec![("Meeting".into(), "loanwords".into()), ("Raum".into(), "general".into())]
+/// );
+/// // Without the forestry lexicon, "Bau" and "Mast" would win, as found first.
+/// assert_eq!(
+///     sources("Baumast"),
+///     vec![("Baum".into(), "forestry".into()), ("Ast".into(), "forestry".into())]
+/// );
+/// ```
+///
+/// ## Errors
+///
+/// Same as [`decompound`](crate::decompound).
+pub fn decompound_with_lexicons<'a>(
+    word: impl AsRef<str>,
+    lexicons: &Lexicons<'_>,
+    config: impl Into<DecompositionConfig<'a>>,
+) -> Result<Vec<Constituent>, DecompositionError> {
+    let cache: RefCell<HashMap<String, Option<usize>>> = RefCell::new(HashMap::new());
+    let lookup = |w: &str| -> Option<usize> {
+        if let Some(&index) = cache.borrow().get(w) {
+            return index;
+        }
+
+        let index = lexicons.find(w);
+        let _ = cache.borrow_mut().insert(w.to_owned(), index);
+        index
+    };
+
+    let is_valid_single_word = |w: &str| lookup(w).is_some();
+    // Constituents valid by other means than the lexicons have no priority.
+    let priority = |w: &str| lookup(w).map(|index| lexicons.lexicons[index].priority);
+
+    let mut config = config.into();
+    config.priority = Some(&priority);
+
+    let mut constituents = decompose(word.as_ref(), &is_valid_single_word, &config)?;
+    for constituent in &mut constituents {
+        // Found valid as is, or only by its lemma.
+        let index = lookup(constituent.form()).or_else(|| constituent.lemma().and_then(lookup));
+        constituent.lexicon = index.map(|index| lexicons.lexicons[index].name.clone());
+    }
+
+    Ok(constituents)
+}
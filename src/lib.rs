@@ -15,11 +15,68 @@
 #![allow(clippy::multiple_crate_versions)]
 #![doc = include_str!("../README.md")]
 
-use std::{collections::BTreeSet, error::Error, fmt::Display};
+use std::{
+    cell::{Cell, RefCell},
+    cmp::Reverse,
+    collections::BTreeSet,
+    error::Error,
+    fmt::Display,
+    iter,
+    ops::Range,
+};
 
+use ambiguity::Ranked;
 use bitflags::bitflags;
+use budget::Budget;
+use hints::Hinted;
 use log::trace;
-use unicode_titlecase::StrTitleCase;
+use strategy::Dictated;
+
+mod ambiguity;
+mod boundary;
+mod budget;
+mod config;
+mod coordination;
+mod decomposition;
+mod diagnostics;
+mod dot;
+mod exceptions;
+mod frequency;
+mod gender;
+mod hints;
+mod hyphenation;
+mod lattice;
+mod lexicons;
+mod morphotactics;
+mod observer;
+mod partial;
+mod strategy;
+mod transform;
+mod tree;
+mod typesetting;
+
+pub use ambiguity::{decompound_with_ambiguity, Ambiguity, AmbiguityReport};
+pub use boundary::german_boundary;
+pub use config::DecompositionConfig;
+pub use coordination::expand_coordination;
+pub use decomposition::{
+    decompound_headed, decompound_with_metadata, Decomposition, Headedness, Role,
+};
+pub use diagnostics::{decompound_with_diagnostics, DiagnosedError, Diagnostics};
+pub use dot::search_space_dot;
+pub use exceptions::Exceptions;
+pub use frequency::FrequencyLexicon;
+pub use gender::GenderMarker;
+pub use hyphenation::HyphenationPatterns;
+pub use lattice::{Lattice, LatticeEdge};
+pub use lexicons::{decompound_with_lexicons, Lexicons};
+pub use morphotactics::{decompound_morphemes, Morpheme, MorphemeClass, Morphotactics};
+pub use observer::{SearchEvent, SearchObserver, SearchStats};
+pub use partial::{decompound_partial, PartialConstituent};
+pub use strategy::SearchStrategy;
+pub use transform::{titlecase, Forms, Position};
+pub use tree::{decompound_tree, Bracketing, CompoundTree};
+pub use typesetting::{mark_boundaries, mark_boundaries_with, BoundaryMark};
 
 /// Error cases for the [`Result`] of [`decompound`].
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -30,8 +87,17 @@ pub enum DecompositionError {
     SingleWord(String),
     /// Nothing valid was found (neither a compound word nor a single, non-compound
     /// word).
-    NothingValid, // Kind of like `Option::None`, but incompatible;
-                  // use obviously different name so it's not confused.
+    //
+    // Kind of like `Option::None`, but incompatible; use obviously different name so
+    // it's not confused.
+    NothingValid,
+    /// The search was given up on, as a limit set in [`DecompositionConfig`] was hit
+    /// (like [`DecompositionConfig::with_max_validator_calls`]), or it was cancelled.
+    /// Holds the best decomposition found until then, if any. It is valid, but might
+    /// not be the one a complete search would have found. For words split on hyphens
+    /// (see [`DecompositionOptions::SPLIT_HYPHENATED`]), there is one only if the search
+    /// ran out in the last part, as the parts after that are not searched at all.
+    BudgetExhausted(Option<Vec<String>>),
 }
 
 impl Display for DecompositionError {
@@ -41,12 +107,52 @@ impl Display for DecompositionError {
                 write!(f, "Not a compound, but valid single word: {word}")
             }
             DecompositionError::NothingValid => write!(f, "No valid decomposition found"),
+            DecompositionError::BudgetExhausted(_) => {
+                write!(f, "Search budget exhausted before finishing")
+            }
         }
     }
 }
 
 impl Error for DecompositionError {}
 
+/// Error cases for the [`Result`] of [`try_decompound`]: those of [`decompound`], or a
+/// failed lookup in the lexicon, of type `E`. Converts from [`DecompositionError`], so
+/// `?` works on the results of both.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TryDecompositionError<E> {
+    /// All lookups succeeded, but decomposition failed as it would for [`decompound`].
+    Decomposition(DecompositionError),
+    /// A lexicon lookup failed, and the search was given up on. Holds the first error
+    /// encountered.
+    Lexicon(E),
+}
+
+impl<E> From<DecompositionError> for TryDecompositionError<E> {
+    fn from(error: DecompositionError) -> Self {
+        TryDecompositionError::Decomposition(error)
+    }
+}
+
+impl<E: Display> Display for TryDecompositionError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TryDecompositionError::Decomposition(error) => write!(f, "{error}"),
+            TryDecompositionError::Lexicon(error) => write!(f, "Lexicon lookup failed: {error}"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for TryDecompositionError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            // Displayed as is, so not a source of its own.
+            TryDecompositionError::Decomposition(error) => error.source(),
+            TryDecompositionError::Lexicon(error) => Some(error),
+        }
+    }
+}
+
 bitflags! {
     /// Options for [`decompound`], configuring its search. Available options are found
     /// below, as `pub const`s.
@@ -54,10 +160,12 @@ bitflags! {
     /// Use [`DecompositionOptions::empty()`] to set *no* options. See the [`bitflags`
     /// docs](https://docs.rs/bitflags/latest/bitflags/#working-with-flags-values) for
     /// more.
-    #[derive(Clone)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
     pub struct DecompositionOptions: u32 {
         /// In *addition* to the original suffix being tried, try its titlecased version
-        /// as well. Does nothing if suffix is already titlecased.
+        /// as well. Does nothing if suffix is already titlecased. This is the built-in
+        /// [`titlecase`] transform; see [`DecompositionConfig::with_transform`] for
+        /// others.
         ///
         /// This option is mostly relevant for languages with meaningful capitalization.
         ///
@@ -236,6 +344,96 @@ bitflags! {
         /// );
         /// ```
         const SHATTER = 1 << 2;
+        /// Recognize gender-inclusive forms, as used in German (`Lehrer*innen`,
+        /// `Lehrer:innen`, `Lehrer_innen` and *Binnen-I* `LehrerInnen`), as valid
+        /// constituents. Such a form is valid if both its base (`Lehrer`) and its
+        /// feminine form (`Lehrerinnen`) are. The constituent is returned *with* its
+        /// marker, which [`GenderMarker::split`] recovers.
+        ///
+        /// ```
+        /// use decompound::{decompound, DecompositionError, DecompositionOptions};
+        ///
+        /// let is_valid_single_word = |w: &str| [
+        ///     "Bürger",
+        ///     "Bürgerinnen",
+        ///     "Beteiligung",
+        /// ].contains(&w);
+        ///
+        /// let word = "Bürger*innenbeteiligung";
+        ///
+        /// // Without this option
+        /// assert_eq!(
+        ///     decompound(
+        ///         word,
+        ///         &is_valid_single_word,
+        ///         DecompositionOptions::TRY_TITLECASE_SUFFIX,
+        ///     ).unwrap_err(),
+        ///     DecompositionError::NothingValid
+        /// );
+        ///
+        /// // With this option
+        /// assert_eq!(
+        ///     decompound(
+        ///         word,
+        ///         &is_valid_single_word,
+        ///         DecompositionOptions::TRY_TITLECASE_SUFFIX
+        ///         | DecompositionOptions::GENDER_INCLUSIVE,
+        ///     ).unwrap(),
+        ///     vec!["Bürger*innen", "Beteiligung"]
+        /// );
+        /// ```
+        const GENDER_INCLUSIVE = 1 << 3;
+        /// Ignore invisible boundary hints, namely soft hyphens (`U+00AD`) and zero
+        /// width non-joiners (`U+200C`), when looking up words, and prefer splits at
+        /// their positions over any others. This is common in text from typeset
+        /// sources.
+        ///
+        /// ```
+        /// use decompound::{decompound, DecompositionError, DecompositionOptions};
+        ///
+        /// let is_valid_single_word = |w: &str| [
+        ///     "Wach",
+        ///     "Stube",
+        ///     "Wachs",
+        ///     "Tube",
+        /// ].contains(&w);
+        ///
+        /// // Ambiguous, lacking any further information:
+        /// assert_eq!(
+        ///     decompound(
+        ///         "Wachstube",
+        ///         &is_valid_single_word,
+        ///         DecompositionOptions::TRY_TITLECASE_SUFFIX,
+        ///     ).unwrap(),
+        ///     vec!["Wach", "Stube"]
+        /// );
+        ///
+        /// // Without this option
+        /// assert_eq!(
+        ///     decompound(
+        ///         "Wachs\u{00AD}tube",
+        ///         &is_valid_single_word,
+        ///         DecompositionOptions::TRY_TITLECASE_SUFFIX,
+        ///     ).unwrap_err(),
+        ///     DecompositionError::NothingValid
+        /// );
+        ///
+        /// // With this option
+        /// assert_eq!(
+        ///     decompound(
+        ///         "Wachs\u{00AD}tube",
+        ///         &is_valid_single_word,
+        ///         DecompositionOptions::TRY_TITLECASE_SUFFIX
+        ///         | DecompositionOptions::BOUNDARY_HINTS,
+        ///     ).unwrap(),
+        ///     vec!["Wachs", "Tube"]
+        /// );
+        /// ```
+        ///
+        /// Use [`decompound_spans`] to map constituents back onto the original word,
+        /// and [`DecompositionConfig::with_mandatory_boundary_hints`] to split at hints
+        /// *exclusively*.
+        const BOUNDARY_HINTS = 1 << 4;
     }
 }
 
@@ -253,149 +451,940 @@ impl AsRef<DecompositionOptions> for DecompositionOptions {
 /// always return owned versions, even when unnecessary.
 pub type DecompositionResult = Result<Vec<String>, DecompositionError>;
 
+/// A single constituent of a decomposition, as returned by [`decompound_spans`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Constituent {
+    pub(crate) span: Range<usize>,
+    pub(crate) form: String,
+    pub(crate) lemma: Option<String>,
+    pub(crate) lexicon: Option<String>,
+}
+
+impl Constituent {
+    /// Byte range of this constituent in the word originally passed in. Boundary
+    /// hints (see [`DecompositionOptions::BOUNDARY_HINTS`]) surrounding the constituent
+    /// are not part of it.
+    #[must_use]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// The form this constituent was found valid as. Might differ from the original
+    /// word's slice at [`Constituent::span`], for example if titlecased.
+    #[must_use]
+    pub fn form(&self) -> &str {
+        &self.form
+    }
+
+    /// The lemma of this constituent's [`Constituent::form`], if lemmatized at all
+    /// and differing from it, like for indexing. It is what this constituent was found
+    /// valid by if its form is not valid itself. See
+    /// [`DecompositionConfig::with_lemmatizer`].
+    #[must_use]
+    pub fn lemma(&self) -> Option<&str> {
+        self.lemma.as_deref()
+    }
+
+    /// The name of the lexicon this constituent was found in, if found by
+    /// [`decompound_with_lexicons`] in one of several.
+    #[must_use]
+    pub fn lexicon(&self) -> Option<&str> {
+        self.lexicon.as_deref()
+    }
+}
+
 /// Refer to the [crate-level documentation](crate) for this item.
 ///
 /// ## Errors
 ///
 /// Errors are covered in the [crate-level documentation](crate#failure-modes).
-pub fn decompound(
+pub fn decompound<'a>(
     word: impl AsRef<str>,
     is_valid_single_word: &impl Fn(&str) -> bool,
-    options: impl AsRef<DecompositionOptions>,
+    config: impl Into<DecompositionConfig<'a>>,
 ) -> DecompositionResult {
-    let mut constituents = vec![];
-    let word = word.as_ref();
-    let options = options.as_ref();
-
-    if options.contains(DecompositionOptions::SPLIT_HYPHENATED) {
-        // Avoid reentry on upcoming recursive call
-        let options = options.clone() - DecompositionOptions::SPLIT_HYPHENATED;
-
-        for subword in word.split('-') {
-            match decompound(subword, is_valid_single_word, &options) {
-                Ok(words) => constituents.extend(words),
-                // Actually allowed in this mode: words like 'string-concatenation' are
-                // valid, where each part is only a 'single' word, not again a compound
-                // word in itself.
-                Err(DecompositionError::SingleWord(word)) => constituents.push(word),
-                _ => return Err(DecompositionError::NothingValid),
-            };
+    decompose(word.as_ref(), is_valid_single_word, &config.into())
+        .map(|constituents| constituents.into_iter().map(|c| c.form).collect())
+}
+
+/// Like [`decompound`], but for lexicons whose lookups may fail, like ones backed by a
+/// file or database. The search is given up on at the first failed lookup, without
+/// any further ones, and its error returned.
+///
+/// ```
+/// use std::io;
+///
+/// use decompound::{try_decompound, DecompositionOptions, TryDecompositionError};
+///
+/// let is_valid_single_word = |w: &str| match w {
+///     "Haus" | "Boot" => Ok(true),
+///     "Steg" => Err(io::Error::new(io::ErrorKind::Other, "disk on fire")),
+///     _ => Ok(false),
+/// };
+///
+/// assert_eq!(
+///     try_decompound(
+///         "Hausboot",
+///         &is_valid_single_word,
+///         DecompositionOptions::TRY_TITLECASE_SUFFIX,
+///     ).unwrap(),
+///     vec!["Haus", "Boot"]
+/// );
+///
+/// match try_decompound(
+///     "Hausbootsteg",
+///     &is_valid_single_word,
+///     DecompositionOptions::TRY_TITLECASE_SUFFIX,
+/// ) {
+///     Err(TryDecompositionError::Lexicon(error)) => {
+///         assert_eq!(error.to_string(), "disk on fire");
+///     }
+///     other => panic!("unexpected {other:?}"),
+/// }
+/// ```
+///
+/// ## Errors
+///
+/// [`TryDecompositionError::Lexicon`] if any lookup failed, otherwise
+/// [`TryDecompositionError::Decomposition`] with the error [`decompound`] would return.
+pub fn try_decompound<'a, E>(
+    word: impl AsRef<str>,
+    is_valid_single_word: &impl Fn(&str) -> Result<bool, E>,
+    config: impl Into<DecompositionConfig<'a>>,
+) -> Result<Vec<String>, TryDecompositionError<E>> {
+    let failure = RefCell::new(None);
+    let aborted = Cell::new(false);
+
+    let is_valid = |w: &str| {
+        if aborted.get() {
+            return false;
         }
 
-        return match &constituents[..] {
-            [] => Err(DecompositionError::NothingValid),
-            [w] => Err(DecompositionError::SingleWord(w.into())),
-            _ => Ok(constituents),
-        };
+        is_valid_single_word(w).unwrap_or_else(|error| {
+            trace!("Lookup of '{w}' failed, aborting");
+            *failure.borrow_mut() = Some(error);
+            aborted.set(true);
+            false
+        })
+    };
+
+    let mut config = config.into();
+    config.aborted = Some(&aborted);
+
+    let result = decompose(word.as_ref(), &is_valid, &config);
+
+    match failure.into_inner() {
+        Some(error) => Err(TryDecompositionError::Lexicon(error)),
+        None => result
+            .map(|constituents| constituents.into_iter().map(|c| c.form).collect())
+            .map_err(TryDecompositionError::Decomposition),
     }
+}
 
-    if is_valid_compound_word(word, is_valid_single_word, options, &mut constituents) {
-        debug_assert!(
-            !constituents.is_empty(),
-            "Compound word must have constituents"
-        );
+/// Like [`decompound`], but keeps track of where in `word` each constituent was found.
+///
+/// ```
+/// use decompound::{decompound_spans, DecompositionOptions};
+///
+/// let is_valid_single_word = |w: &str| ["Auf", "Lage"].contains(&w);
+///
+/// let word = "Auf\u{00AD}lage";
+/// let constituents = decompound_spans(
+///     word,
+///     &is_valid_single_word,
+///     DecompositionOptions::BOUNDARY_HINTS | DecompositionOptions::TRY_TITLECASE_SUFFIX,
+/// ).unwrap();
+///
+/// assert_eq!(constituents[1].form(), "Lage");
+/// assert_eq!(&word[constituents[1].span()], "lage");
+/// ```
+///
+/// ## Errors
+///
+/// Same as [`decompound`].
+pub fn decompound_spans<'a>(
+    word: impl AsRef<str>,
+    is_valid_single_word: &impl Fn(&str) -> bool,
+    config: impl Into<DecompositionConfig<'a>>,
+) -> Result<Vec<Constituent>, DecompositionError> {
+    decompose(word.as_ref(), is_valid_single_word, &config.into())
+}
+
+pub(crate) fn decompose(
+    word: &str,
+    is_valid_single_word: &impl Fn(&str) -> bool,
+    config: &DecompositionConfig<'_>,
+) -> Result<Vec<Constituent>, DecompositionError> {
+    decompose_ranked(word, is_valid_single_word, config).map(|ranked| ranked.best)
+}
+
+pub(crate) fn decompose_ranked(
+    word: &str,
+    is_valid_single_word: &impl Fn(&str) -> bool,
+    config: &DecompositionConfig<'_>,
+) -> Result<Ranked, DecompositionError> {
+    let budget = Budget::new(config);
+
+    if !config.strips_boundary_hints() {
+        return decompose_word(word, &[], is_valid_single_word, config, &budget);
+    }
+
+    let hinted = Hinted::new(word);
+    trace!(
+        "Stripped boundary hints, looking up '{}'",
+        hinted.stripped()
+    );
+
+    decompose_word(
+        hinted.stripped(),
+        hinted.hints(),
+        is_valid_single_word,
+        config,
+        &budget,
+    )
+    .map(|ranked| ranked.map_spans(|span| hinted.restore(span)))
+}
+
+/// Decomposes `word` as a whole, applying constraints which concern all of it, not
+/// just its hyphen-separated parts. Hints are as for [`decompose_hinted`].
+fn decompose_word(
+    word: &str,
+    hints: &[usize],
+    is_valid_single_word: &impl Fn(&str) -> bool,
+    config: &DecompositionConfig<'_>,
+    budget: &Budget<'_, '_>,
+) -> Result<Ranked, DecompositionError> {
+    // Looked up like any constituent during search, so within budget.
+    let whole_word = Search {
+        word,
+        hints,
+        split_points: None,
+        is_valid_single_word,
+        config,
+        budget,
+    };
+
+    if word.chars().count() < config.min_word_len {
+        trace!("Word '{word}' too short to decompose");
+
+        return Err(whole_word.undecomposable());
+    }
+
+    let ranked = decompose_hinted(word, hints, is_valid_single_word, config, budget)?;
+
+    match config.max_constituents {
+        Some(max) if ranked.best.len() > max => {
+            trace!("Too many constituents for '{word}'");
+
+            Err(whole_word.undecomposable())
+        }
+        _ => Ok(ranked),
+    }
+}
+
+/// Decomposes `word`, which is free of boundary hints, but had them at the byte
+/// offsets in `hints`.
+pub(crate) fn decompose_hinted(
+    word: &str,
+    hints: &[usize],
+    is_valid_single_word: &impl Fn(&str) -> bool,
+    config: &DecompositionConfig<'_>,
+    budget: &Budget<'_, '_>,
+) -> Result<Ranked, DecompositionError> {
+    if config
+        .options
+        .contains(DecompositionOptions::SPLIT_HYPHENATED)
+    {
+        return decompose_hyphenated(word, hints, is_valid_single_word, config, budget);
+    }
+
+    let search = Search {
+        word,
+        hints,
+        split_points: config
+            .hyphenation_patterns
+            .map(|patterns| patterns.hyphenation_points(word)),
+        is_valid_single_word,
+        config,
+        budget,
+    };
 
-        Ok(constituents)
-    } else {
-        trace!("Word is not a valid compound word");
+    match search.run() {
+        Some(ranked) if budget.is_exhausted() => Err(DecompositionError::BudgetExhausted(Some(
+            ranked.best.into_iter().map(|c| c.form).collect(),
+        ))),
+        Some(ranked) if search.outranked_by_whole_word(&ranked.best) => {
+            trace!("Word ranks higher undecomposed");
 
-        if is_valid_single_word(word) {
             Err(DecompositionError::SingleWord(word.to_owned()))
-        } else {
-            Err(DecompositionError::NothingValid)
+        }
+        Some(ranked) => {
+            debug_assert!(
+                !ranked.best.is_empty(),
+                "Compound word must have constituents"
+            );
+
+            Ok(ranked)
+        }
+        None => {
+            trace!("Word is not a valid compound word");
+
+            if search
+                .valid_constituent(0..word.len(), word.to_owned())
+                .is_some()
+            {
+                Err(DecompositionError::SingleWord(word.to_owned()))
+            } else if budget.is_exhausted() {
+                // Possibly before even looking up the word as a whole.
+                Err(DecompositionError::BudgetExhausted(None))
+            } else {
+                Err(DecompositionError::NothingValid)
+            }
         }
     }
 }
 
-fn is_valid_compound_word(
-    word: impl AsRef<str>,
+/// Decomposes `word` part by part, as split on hyphens, see
+/// [`DecompositionOptions::SPLIT_HYPHENATED`].
+fn decompose_hyphenated(
+    word: &str,
+    hints: &[usize],
     is_valid_single_word: &impl Fn(&str) -> bool,
-    options: &DecompositionOptions,
-    constituents: &mut Vec<String>,
-) -> bool {
-    let word = word.as_ref();
-    trace!("Checking if word is valid compound word: '{}'", word);
+    config: &DecompositionConfig<'_>,
+    budget: &Budget<'_, '_>,
+) -> Result<Ranked, DecompositionError> {
+    // Avoid reentry on upcoming recursive call
+    let mut config = config.clone();
+    config.options -= DecompositionOptions::SPLIT_HYPHENATED;
 
-    let mut all_valid_splits = Vec::new();
+    // Only the whole word has a head: that of its outermost part. The heads of all
+    // other parts are judged as modifiers.
+    let role_filter = config.role_filter;
+    let as_modifier =
+        move |form: &str, _: Role| role_filter.map_or(true, |filter| filter(form, Role::Modifier));
 
-    for (i, _) in word.char_indices().skip(1) {
-        // Try *all* prefixes, not just the first or longest valid one; they all might
-        // have valid suffixes. Which one to return is decided later.
-        let (prefix, suffix) = word.split_at(i);
+    let subwords: Vec<&str> = word.split('-').collect();
+    let mut parts: Vec<Ranked> = Vec::new();
+    let mut offset = 0;
+    for (i, &subword) in subwords.iter().enumerate() {
+        let shift = |span: &Range<usize>| (span.start + offset)..(span.end + offset);
 
-        debug_assert!(!prefix.is_empty(), "Prefix should never be empty");
-        debug_assert!(!suffix.is_empty(), "Suffix should never be empty");
+        // The limit is on all parts together: leave at least one constituent to
+        // each part yet to come.
+        let mut part_config = config.clone();
+        if let Some(max) = config.max_constituents {
+            let used: usize = parts.iter().map(|part| part.best.len()).sum();
+            let to_come = subwords.len() - i - 1;
+            part_config.max_constituents = Some(max.saturating_sub(used + to_come));
+        }
 
-        if !is_valid_single_word(prefix) {
-            continue;
+        let is_head_part = match config.headedness {
+            Headedness::Right => i == subwords.len() - 1,
+            Headedness::Left => i == 0,
+        };
+        if !is_head_part && role_filter.is_some() {
+            part_config.role_filter = Some(&as_modifier);
         }
 
-        trace!(
-            "Prefix '{}' found to be valid, seeing if suffix '{}' is valid.",
-            prefix,
-            suffix
-        );
+        let subhints: Vec<usize> = hints
+            .iter()
+            .filter(|&&h| h >= offset && h <= offset + subword.len())
+            .map(|h| h - offset)
+            .collect();
 
-        let suffix_candidates = {
-            // Dedupe so no unnecessary work is done, but keep order for determinism
-            let mut set = BTreeSet::from_iter(vec![suffix.to_owned()]);
+        match decompose_hinted(
+            subword,
+            &subhints,
+            is_valid_single_word,
+            &part_config,
+            budget,
+        ) {
+            Ok(ranked) => parts.push(ranked.map_spans(shift)),
+            // Actually allowed in this mode: words like 'string-concatenation' are
+            // valid, where each part is only a 'single' word, not again a compound
+            // word in itself.
+            Err(DecompositionError::SingleWord(word)) => {
+                parts.push(Ranked::unique(vec![Constituent {
+                    span: shift(&(0..subword.len())),
+                    form: word,
+                    lemma: None,
+                    lexicon: None,
+                }]));
+            }
+            Err(DecompositionError::BudgetExhausted(best)) => {
+                // Only parts yet to come are not searched at all, so there is a
+                // decomposition of the whole word only if running out in the last part.
+                let best = best.filter(|_| i == subwords.len() - 1).map(|best| {
+                    parts
+                        .iter()
+                        .flat_map(|part| part.best.iter().map(|c| c.form.clone()))
+                        .chain(best)
+                        .collect()
+                });
 
-            if options.contains(DecompositionOptions::TRY_TITLECASE_SUFFIX) {
-                let _ = set.insert(suffix.to_titlecase_lower_rest());
+                return Err(DecompositionError::BudgetExhausted(best));
             }
+            _ => return Err(DecompositionError::NothingValid),
+        }
+
+        offset += subword.len() + '-'.len_utf8();
+    }
+
+    let ranked = Ranked::join(&parts);
+
+    match &ranked.best[..] {
+        [] => Err(DecompositionError::NothingValid),
+        [c] => Err(DecompositionError::SingleWord(c.form.clone())),
+        _ => Ok(ranked),
+    }
+}
+
+/// State of the search for a decomposition of a single word, free of hyphens (if split
+/// on) and boundary hints.
+struct Search<'s, 'c, F> {
+    word: &'s str,
+    /// Byte offsets into `word` at which boundary hints were found.
+    hints: &'s [usize],
+    /// Byte offsets into `word` which are allowed as split points, if restricted.
+    split_points: Option<Vec<usize>>,
+    is_valid_single_word: &'s F,
+    config: &'s DecompositionConfig<'c>,
+    budget: &'s Budget<'s, 'c>,
+}
+
+/// Checks whether `form` is valid as a single constituent, which might be more lenient
+/// than `is_valid_single_word` alone depending on options.
+fn is_valid_constituent(
+    form: &str,
+    is_valid_single_word: &impl Fn(&str) -> bool,
+    config: &DecompositionConfig<'_>,
+) -> bool {
+    if config.exceptions.map_or(false, |e| e.is_denied(form)) {
+        trace!("'{form}' is a denied constituent");
+        return false;
+    }
+
+    is_valid_single_word(form)
+        || (config
+            .options
+            .contains(DecompositionOptions::GENDER_INCLUSIVE)
+            && gender::is_valid(form, is_valid_single_word))
+}
+
+impl<F: Fn(&str) -> bool> Search<'_, '_, F> {
+    fn observe(&self, event: &SearchEvent<'_>) {
+        if let Some(observer) = self.config.observer {
+            observer.on_event(event);
+        }
+    }
 
-            set
+    /// Why the word cannot be decomposed, judging by whether it is valid as a whole.
+    fn undecomposable(&self) -> DecompositionError {
+        if self.is_valid_constituent(self.word) {
+            DecompositionError::SingleWord(self.word.to_owned())
+        } else if self.budget.is_exhausted() {
+            DecompositionError::BudgetExhausted(None)
+        } else {
+            DecompositionError::NothingValid
+        }
+    }
+
+    fn is_valid_constituent(&self, form: &str) -> bool {
+        let is_valid_single_word = |w: &str| {
+            if !self.budget.spend_validator_call() {
+                return false;
+            }
+
+            self.observe(&SearchEvent::ValidatorCalled { form: w });
+            (self.is_valid_single_word)(w)
         };
 
-        debug_assert!(
-            !suffix_candidates.is_empty(),
-            "Suffix candidates should never be empty"
+        is_valid_constituent(form, &is_valid_single_word, self.config)
+    }
+
+    /// Checks whether `form`, found at `word[span]`, is a valid constituent.
+    fn is_valid_candidate(&self, span: Range<usize>, form: &str) -> bool {
+        let valid = self.is_valid_constituent(form);
+
+        self.observe(&SearchEvent::CandidateChecked {
+            transformed: form != &self.word[span.clone()],
+            span,
+            form,
+            valid,
+        });
+
+        valid
+    }
+
+    /// `form`, found at `word[span]`, as a constituent if it is valid: as is, or by its
+    /// lemma (see [`DecompositionConfig::with_lemmatizer`]). Either way, it carries its
+    /// lemma, if any.
+    fn valid_constituent(&self, span: Range<usize>, form: String) -> Option<Constituent> {
+        if self.is_valid_candidate(span.clone(), &form) {
+            if !self.fits_role(&span, &form) {
+                return None;
+            }
+
+            let lemma = self.lemma(&span, &form);
+            return Some(Constituent {
+                span,
+                form,
+                lemma,
+                lexicon: None,
+            });
+        }
+
+        let lemma = self.lemma(&span, &form)?;
+        let valid = self.is_valid_constituent(&lemma);
+        self.observe(&SearchEvent::LemmaChecked {
+            span: span.clone(),
+            form: &form,
+            lemma: &lemma,
+            valid,
+        });
+
+        if !valid || !self.fits_role(&span, &lemma) {
+            return None;
+        }
+
+        trace!("'{form}' is valid by its lemma '{lemma}'");
+        Some(Constituent {
+            span,
+            form,
+            lemma: Some(lemma),
+            lexicon: None,
+        })
+    }
+
+    /// The lemma of `form`, found at `word[span]`, if it is to be lemmatized there and
+    /// has one other than itself.
+    fn lemma(&self, span: &Range<usize>, form: &str) -> Option<String> {
+        let lemmatizer = self.config.lemmatizer?;
+        if span.end != self.word.len() && !self.config.lemmatize_modifiers {
+            return None;
+        }
+
+        lemmatizer(form).filter(|lemma| !lemma.is_empty() && lemma != form)
+    }
+
+    /// Checks whether valid `form`, found at `word[span]`, is allowed in its role
+    /// there, if restricted.
+    fn fits_role(&self, span: &Range<usize>, form: &str) -> bool {
+        let role_filter = match self.config.role_filter {
+            Some(role_filter) => role_filter,
+            None => return true,
+        };
+
+        let is_head = match self.config.headedness {
+            Headedness::Right => span.end == self.word.len(),
+            Headedness::Left => span.start == 0,
+        };
+        let role = if is_head { Role::Head } else { Role::Modifier };
+
+        let fits = role_filter(form, role);
+        if !fits {
+            trace!("'{form}' is not allowed as {role:?}");
+        }
+
+        fits
+    }
+
+    /// Checks whether the word may be split at byte offset `split` at all, with the
+    /// current constituent starting at `start`, regardless of the validity of what's
+    /// left and right of it.
+    fn may_split_at(&self, start: usize, split: usize) -> bool {
+        if !self.budget.spend_explored_split() {
+            return false;
+        }
+
+        let at_hint = self.hints.contains(&split);
+
+        if self.config.mandatory_boundary_hints && !at_hint {
+            return false;
+        }
+
+        // Whatever is right of the split will be at least one constituent.
+        let min_len = self.config.min_constituent_len;
+        if self.word[start..split].chars().count() < min_len
+            || self.word[split..].chars().count() < min_len
+        {
+            return false;
+        }
+
+        if let Some(points) = &self.split_points {
+            // Hints are explicit hyphenation points, so always allowed.
+            if !at_hint && !points.contains(&split) {
+                trace!("Not splitting at {split}, not a hyphenation point");
+                return false;
+            }
+        }
+
+        if self
+            .config
+            .options
+            .contains(DecompositionOptions::GENDER_INCLUSIVE)
+            && gender::cuts_marker(&self.word[..split], &self.word[split..])
+        {
+            trace!("Not splitting gender-inclusive form at {split}");
+            return false;
+        }
+
+        if let Some(predicate) = self.config.boundary_predicate {
+            if !predicate(&self.word[start..split], &self.word[split..]) {
+                trace!("Not splitting at {split}, rejected by boundary predicate");
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// The curated split of `word[start..]`, looked up as `form`, if any.
+    fn fixed_split(&self, start: usize, form: &str) -> Option<Vec<Constituent>> {
+        let parts = self.config.exceptions?.fixed_split(form)?;
+        if parts.len() < 2 {
+            return None;
+        }
+
+        let boundaries: Vec<usize> = self.word[start..]
+            .char_indices()
+            .map(|(i, _)| start + i)
+            .chain(iter::once(self.word.len()))
+            .collect();
+
+        let mut constituents = Vec::with_capacity(parts.len());
+        let mut chars = 0;
+        for part in parts {
+            let begin = boundaries[chars];
+            chars += part.chars().count();
+
+            let end = *boundaries.get(chars)?;
+            if begin == end || part.to_lowercase() != self.word[begin..end].to_lowercase() {
+                trace!("Fixed split of '{form}' does not spell it out, ignoring it");
+                return None;
+            }
+
+            constituents.push(Constituent {
+                span: begin..end,
+                form: part.clone(),
+                lemma: None,
+                lexicon: None,
+            });
+        }
+
+        if chars + 1 == boundaries.len() {
+            Some(constituents)
+        } else {
+            trace!("Fixed split of '{form}' does not spell it out, ignoring it");
+            None
+        }
+    }
+
+    /// Checks whether `word[start..]` is a valid compound word. All spans pushed onto
+    /// `constituents` are relative to `word`, not to `start`. `depth` is the number of
+    /// constituents preceding `start`.
+    fn is_valid_compound_word(
+        &self,
+        start: usize,
+        depth: usize,
+        constituents: &mut Vec<Constituent>,
+    ) -> bool {
+        match self.select(&self.valid_splits(start, depth)) {
+            Some(split) => {
+                self.observe(&SearchEvent::SplitSelected {
+                    constituents: split,
+                    depth,
+                });
+                constituents.extend(split.iter().cloned());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// All valid decompositions of `word[start..]` found, each with the best
+    /// decomposition of its suffix. Arguments are as for
+    /// [`Search::is_valid_compound_word`].
+    fn valid_splits(&self, start: usize, depth: usize) -> Vec<Vec<Constituent>> {
+        let word = self.word;
+        trace!(
+            "Checking if word is valid compound word: '{}'",
+            &word[start..]
         );
 
-        for suffix in suffix_candidates {
-            // EACH of these checks (for readability, organized into blocks) might be
-            // true, so we do not break early, and clone copiously (could be done more
-            // efficiently at substantially higher complexity).
-            //
-            // A word might be *both* a valid single *and* compound word, but which
-            // version is kept depends on `SHATTER`ing.
+        match self.dictated(start) {
+            Some(Dictated::Fixed(fixed)) => return vec![fixed],
+            Some(Dictated::Whole) => return Vec::new(),
+            None => {}
+        }
 
-            {
-                if is_valid_single_word(&suffix) {
-                    trace!("Suffix '{}' is valid: valid single word", suffix);
-                    all_valid_splits.push(vec![prefix.to_owned(), suffix.clone()]);
-                }
+        let mut all_valid_splits = Vec::new();
+
+        // A split of this part makes for at least two more constituents, recursing into
+        // its suffix for at least three.
+        let max_len = self
+            .config
+            .max_constituents
+            .map_or(usize::MAX, |max| max.saturating_sub(depth));
+        let may_recurse = max_len >= 3 && self.config.max_depth.map_or(true, |max| depth < max);
+
+        for (i, _) in word[start..].char_indices().skip(1) {
+            // Try *all* prefixes, not just the first or longest valid one; they all might
+            // have valid suffixes. Which one to return is decided later.
+            if self.budget.is_exhausted() {
+                break;
             }
 
-            {
-                let mut further_constituents = Vec::new();
+            let split = start + i;
+            debug_assert!(split < word.len(), "Suffix should never be empty");
+
+            if !self.may_split_at(start, split) {
+                continue;
+            }
+
+            let prefixes = self.valid_prefixes(start..split, depth);
+            if prefixes.is_empty() {
+                continue;
+            }
 
-                if is_valid_compound_word(
-                    &suffix,
-                    is_valid_single_word,
-                    options,
-                    &mut further_constituents,
-                ) {
-                    trace!("Suffix '{}' is valid: valid compound word", suffix);
+            trace!(
+                "Prefix '{}' found to be valid, seeing if suffix '{}' is valid.",
+                &word[start..split],
+                &word[split..]
+            );
 
-                    let mut valid_split = vec![prefix.to_owned()];
-                    valid_split.extend(further_constituents);
+            let suffix_splits = self.suffix_splits(split, depth, may_recurse);
+            for prefix in &prefixes {
+                for suffix_split in &suffix_splits {
+                    let mut valid_split = vec![prefix.clone()];
+                    valid_split.extend(suffix_split.iter().cloned());
 
+                    self.observe(&SearchEvent::SplitRecorded {
+                        constituents: &valid_split,
+                        depth,
+                    });
                     all_valid_splits.push(valid_split);
                 }
             }
         }
+
+        all_valid_splits.retain(|s| s.len() <= max_len);
+        all_valid_splits
+    }
+
+    /// All forms `word[span]` is valid as, as prefixes of the rest of the word.
+    fn valid_prefixes(&self, span: Range<usize>, depth: usize) -> Vec<Constituent> {
+        let mut prefixes = Vec::new();
+
+        for form in self.candidates(span.clone()) {
+            debug_assert!(!form.is_empty(), "Prefix should never be empty");
+
+            self.observe(&SearchEvent::PrefixTried {
+                span: span.clone(),
+                form: &form,
+                depth,
+            });
+            let prefix = match self.valid_constituent(span.clone(), form) {
+                Some(prefix) => prefix,
+                None => continue,
+            };
+            self.observe(&SearchEvent::PrefixAccepted {
+                span: span.clone(),
+                form: &prefix.form,
+                depth,
+            });
+
+            prefixes.push(prefix);
+        }
+
+        prefixes
+    }
+
+    /// All valid decompositions of `word[split..]`, following a valid prefix: as a
+    /// single constituent in any of its forms, and as a compound, if `may_recurse`.
+    fn suffix_splits(
+        &self,
+        split: usize,
+        depth: usize,
+        may_recurse: bool,
+    ) -> Vec<Vec<Constituent>> {
+        let span = split..self.word.len();
+        let mut suffix_splits = Vec::new();
+
+        // EACH of these checks might be true, so we do not break early, and clone
+        // copiously (could be done more efficiently at substantially higher
+        // complexity).
+        //
+        // A word might be *both* a valid single *and* compound word, but which version
+        // is kept depends on `SHATTER`ing.
+        for suffix in self.candidates(span.clone()) {
+            self.observe(&SearchEvent::SuffixCandidate {
+                span: span.clone(),
+                form: &suffix,
+                depth,
+            });
+
+            // A suffix with a curated split is never a single constituent.
+            let has_fixed_split = self.fixed_split(split, &suffix).is_some();
+
+            if has_fixed_split {
+                continue;
+            }
+
+            if let Some(suffix) = self.valid_constituent(span.clone(), suffix) {
+                trace!("Suffix '{}' is valid: valid single word", suffix.form);
+                suffix_splits.push(vec![suffix]);
+            }
+        }
+
+        if may_recurse && self.config.scorer.is_some() {
+            // Scores need not carry over from suffixes to the words they end, so keep
+            // every decomposition of the suffix and only compare complete ones.
+            suffix_splits.extend(self.valid_splits(split, depth + 1));
+        } else if may_recurse {
+            let mut further_constituents = Vec::new();
+
+            if self.is_valid_compound_word(split, depth + 1, &mut further_constituents) {
+                trace!(
+                    "Suffix '{}' is valid: valid compound word",
+                    &self.word[split..]
+                );
+                suffix_splits.push(further_constituents);
+            }
+        }
+
+        suffix_splits
+    }
+
+    /// All forms to look up `word[span]` as, in order of preference.
+    pub(crate) fn forms(&self, span: Range<usize>) -> Vec<String> {
+        let position = Position::of(&span, self.word.len());
+        transform::forms(&self.word[span], position, self.config)
+    }
+
+    /// Like [`Search::forms`], but sorted, for exhaustively trying all of them.
+    fn candidates(&self, span: Range<usize>) -> BTreeSet<String> {
+        // Dedupe so no unnecessary work is done, but keep order for determinism
+        self.forms(span).into_iter().collect()
+    }
+
+    /// How `split` ranks: first by the number of its constituents starting at boundary
+    /// hints, then by its score, higher being better for both.
+    #[allow(clippy::cast_precision_loss)] // No word has 2^52 constituents.
+    pub(crate) fn rank_key(&self, split: &[Constituent]) -> (usize, f64) {
+        // Without any hints, this is always zero.
+        let at_hints = split
+            .iter()
+            .skip(1)
+            .filter(|c| self.hints.contains(&c.span.start))
+            .count();
+
+        let score = if let Some(scorer) = self.config.scorer {
+            let forms: Vec<&str> = split.iter().map(|c| c.form.as_str()).collect();
+            scorer.score(&forms)
+        } else if self.config.options.contains(DecompositionOptions::SHATTER) {
+            split.len() as f64
+        } else {
+            -(split.len() as f64)
+        };
+
+        (at_hints, score)
+    }
+
+    /// Whether, with a scorer set, the word as a single constituent ranks at least as
+    /// high as `best`, its best decomposition. Without a scorer, decompositions always
+    /// win.
+    fn outranked_by_whole_word(&self, best: &[Constituent]) -> bool {
+        if self.config.scorer.is_none() {
+            return false;
+        }
+
+        self.valid_constituent(0..self.word.len(), self.word.to_owned())
+            .map_or(false, |whole| {
+                self.rank_key(&[whole]) >= self.rank_key(best)
+            })
     }
 
-    match if options.contains(DecompositionOptions::SHATTER) {
-        all_valid_splits.iter().max_by_key(|s| s.len())
-    } else {
-        all_valid_splits.iter().min_by_key(|s| s.len())
-    } {
-        Some(split) => {
-            constituents.extend(split.iter().cloned());
-            true
+    /// Picks the split to go with out of all valid ones.
+    fn select<'v>(&self, splits: &'v [Vec<Constituent>]) -> Option<&'v Vec<Constituent>> {
+        // Splits at boundary hints take precedence over the number of constituents.
+        let at_hints = |split: &Vec<Constituent>| self.rank_key(split).0;
+
+        if self.config.scorer.is_some() {
+            let mut best: Option<(&Vec<Constituent>, (usize, f64))> = None;
+
+            for split in splits {
+                let key = self.rank_key(split);
+
+                // Strictly greater only, so the first of equally scored splits wins.
+                if best.map_or(true, |(_, best_key)| key > best_key) {
+                    best = Some((split, key));
+                }
+            }
+
+            best.map(|(split, _)| split)
+        } else if self.config.options.contains(DecompositionOptions::SHATTER) {
+            splits
+                .iter()
+                .max_by_key(|s| (at_hints(s), s.len(), self.priority(s)))
+        } else {
+            splits
+                .iter()
+                .min_by_key(|s| (Reverse(at_hints(s)), s.len(), Reverse(self.priority(s))))
         }
-        None => false,
+    }
+
+    /// Total priority of the lexicons of all constituents of `split`, zero without
+    /// any (see [`decompound_with_lexicons`]).
+    fn priority(&self, split: &[Constituent]) -> i64 {
+        self.config.priority.map_or(0, |priority| {
+            split
+                .iter()
+                .map(|c| {
+                    let found = priority(&c.form).or_else(|| c.lemma.as_deref().and_then(priority));
+                    i64::from(found.unwrap_or(0))
+                })
+                .sum()
+        })
+    }
+
+    /// Ranks all `candidates` for the entire word, see [`Ranked`].
+    pub(crate) fn rank(&self, candidates: &[Vec<Constituent>]) -> Option<Ranked> {
+        let best = self.select(candidates)?;
+
+        let spans = |split: &Vec<Constituent>| -> Vec<Range<usize>> {
+            split.iter().map(|c| c.span.clone()).collect()
+        };
+        // Merely different forms of the same split are no alternative.
+        let others: Vec<Vec<Constituent>> = candidates
+            .iter()
+            .filter(|c| spans(c) != spans(best))
+            .cloned()
+            .collect();
+        let runner_up = self.select(&others).cloned();
+
+        let (best_hints, best_score) = self.rank_key(best);
+        let margin = match &runner_up {
+            Some(runner_up) => match self.rank_key(runner_up) {
+                (hints, score) if hints == best_hints => best_score - score,
+                _ => f64::INFINITY,
+            },
+            None => f64::INFINITY,
+        };
+
+        let ambiguity = if margin <= 0.0 {
+            Ambiguity::Tie
+        } else if margin <= self.config.near_tie_margin * best_score.abs() {
+            Ambiguity::NearTie
+        } else {
+            Ambiguity::Unique
+        };
+
+        Some(Ranked {
+            best: best.clone(),
+            runner_up,
+            margin,
+            ambiguity,
+        })
     }
 }
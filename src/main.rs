@@ -1,8 +1,39 @@
-use decompound::{decompound, DecompositionOptions};
+use decompound::{
+    decompound, search_space_dot, DecompositionConfig, DecompositionOptions, FrequencyLexicon,
+};
 use std::{collections::HashSet, env, io::stdin};
 
+/// Command line arguments, see [`parse`].
+struct Args {
+    options: DecompositionOptions,
+    word: String,
+    frequencies: Option<String>,
+    dot: bool,
+}
+
 fn main() -> Result<(), &'static str> {
-    let (options, word) = parse()?;
+    let Args {
+        options,
+        word,
+        frequencies,
+        dot,
+    } = parse()?;
+
+    let frequencies = match frequencies {
+        Some(path) => {
+            eprintln!("Reading frequencies from '{path}'...");
+            let frequencies =
+                FrequencyLexicon::from_file(path).map_err(|_| "Failed to read frequencies")?;
+            eprintln!("Read {} frequencies.", frequencies.len());
+            Some(frequencies)
+        }
+        None => None,
+    };
+
+    let mut config = DecompositionConfig::new(options);
+    if let Some(frequencies) = &frequencies {
+        config = config.with_frequencies(frequencies);
+    }
 
     eprintln!("Reading list of valid (single) words from stdin...");
 
@@ -13,12 +44,21 @@ fn main() -> Result<(), &'static str> {
         .collect();
 
     eprintln!("Read {} words.", valid_words.len());
-    eprintln!("Constituents of '{}' are:", word);
 
-    match decompound(word, &|w| valid_words.contains(&w.to_owned()), options) {
+    let is_valid_single_word = |w: &str| valid_words.contains(w);
+
+    if dot {
+        eprintln!("Search space of '{word}' is:");
+        print!("{}", search_space_dot(word, &is_valid_single_word, config));
+        return Ok(());
+    }
+
+    eprintln!("Constituents of '{word}' are:");
+
+    match decompound(word, &is_valid_single_word, config) {
         Ok(words) => {
             for word in words {
-                println!("{}", word);
+                println!("{word}");
             }
             Ok(())
         }
@@ -38,17 +78,20 @@ fn main() -> Result<(), &'static str> {
 /// https://github.com/rust-lang/cargo/issues/1982
 ///
 /// https://users.rust-lang.org/t/whats-the-convention-for-handling-a-hybrid-library-and-binary-crates-dependencies/84174
-fn parse() -> Result<(DecompositionOptions, String), &'static str> {
+fn parse() -> Result<Args, &'static str> {
     let mut args: Vec<String> = env::args().collect();
-    eprintln!("Args: {:?}", args);
+    eprintln!("Args: {args:?}");
 
     args.remove(0); // Program name
 
     let mut options = DecompositionOptions::empty();
     let mut word = None;
+    let mut frequencies = None;
+    let mut dot = false;
 
-    for arg in args {
-        eprintln!("Arg: {}", arg);
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        eprintln!("Arg: {arg}");
 
         match arg.as_str() {
             "-t" | "--try-titlecase-suffix" => {
@@ -59,16 +102,33 @@ fn parse() -> Result<(DecompositionOptions, String), &'static str> {
                 eprintln!("Will split hyphenated words");
                 options |= DecompositionOptions::SPLIT_HYPHENATED
             }
+            "-g" | "--gender-inclusive" => {
+                eprintln!("Will recognize gender-inclusive forms");
+                options |= DecompositionOptions::GENDER_INCLUSIVE
+            }
+            "-b" | "--boundary-hints" => {
+                eprintln!("Will prefer splits at boundary hints");
+                options |= DecompositionOptions::BOUNDARY_HINTS
+            }
+            "-f" | "--frequencies" => {
+                let path = args.next().ok_or("Missing path to frequencies")?;
+                eprintln!("Will rank by frequencies from: {path}");
+                frequencies = Some(path);
+            }
+            "--dot" => {
+                eprintln!("Will print the search space as a Graphviz graph");
+                dot = true;
+            }
             "--shatter" => {
                 eprintln!("Will shatter words");
                 options |= DecompositionOptions::SHATTER
             }
             a if a.starts_with('-') => {
-                eprintln!("Unknown option: {}", a);
+                eprintln!("Unknown option: {a}");
                 return Err("Unknown option");
             }
             a => {
-                eprintln!("Setting word to: {}", a);
+                eprintln!("Setting word to: {a}");
                 word = Some(a.to_owned());
             }
         }
@@ -76,5 +136,10 @@ fn parse() -> Result<(DecompositionOptions, String), &'static str> {
 
     let word = word.expect("No word detected");
 
-    Ok((options, word))
+    Ok(Args {
+        options,
+        word,
+        frequencies,
+        dot,
+    })
 }
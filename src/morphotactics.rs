@@ -0,0 +1,343 @@
+//! Segmentation of words into morphemes, according to a finite-state grammar.
+
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Range,
+};
+
+use crate::{
+    hints::Hinted, transform, DecompositionConfig, DecompositionError, DecompositionOptions,
+    Position,
+};
+
+/// A class of morphemes, as a state of [`Morphotactics`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MorphemeClass {
+    /// A bound morpheme preceding a stem, like German *un-* or *ver-*.
+    Prefix,
+    /// A free morpheme, a word in its own right, like German *Haus*.
+    Stem,
+    /// A linking element between the parts of a compound, like the German *Fugen-s*.
+    Linker,
+    /// A bound morpheme deriving a new word from a stem, like German *-ung* or *-heit*.
+    Derivation,
+    /// A bound morpheme inflecting a word, like German *-en* or *-s*.
+    Inflection,
+}
+
+impl MorphemeClass {
+    const ALL: [MorphemeClass; 5] = [
+        MorphemeClass::Prefix,
+        MorphemeClass::Stem,
+        MorphemeClass::Linker,
+        MorphemeClass::Derivation,
+        MorphemeClass::Inflection,
+    ];
+}
+
+/// A small finite-state grammar of which [`MorphemeClass`]es may follow each other in
+/// a word, for [`decompound_morphemes`]. Its states are the morpheme classes: which
+/// class may come next only depends on the class of the morpheme before.
+///
+/// ```
+/// use decompound::{MorphemeClass::*, Morphotactics};
+///
+/// // Stems, each with an optional inflection
+/// let grammar = Morphotactics::new()
+///     .with_initial(Stem)
+///     .with_transition(Stem, Stem)
+///     .with_transition(Stem, Inflection)
+///     .with_transition(Inflection, Stem)
+///     .with_final(Stem)
+///     .with_final(Inflection);
+///
+/// assert!(grammar.allows(&[Stem, Inflection, Stem]));
+/// assert!(!grammar.allows(&[Inflection, Stem]));
+/// assert!(!grammar.allows(&[Stem, Inflection, Inflection]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Morphotactics {
+    initial: HashSet<MorphemeClass>,
+    transitions: HashSet<(MorphemeClass, MorphemeClass)>,
+    finals: HashSet<MorphemeClass>,
+}
+
+impl Morphotactics {
+    /// Creates a grammar allowing nothing.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A grammar for German: any number of prefixes, then a stem followed by any
+    /// number of derivations, ending in an inflection or repeating with a linker or
+    /// directly.
+    #[must_use]
+    pub fn german() -> Self {
+        use MorphemeClass::{Derivation, Inflection, Linker, Prefix, Stem};
+
+        Self::new()
+            .with_initial(Prefix)
+            .with_initial(Stem)
+            .with_transition(Prefix, Prefix)
+            .with_transition(Prefix, Stem)
+            .with_transition(Stem, Prefix)
+            .with_transition(Stem, Stem)
+            .with_transition(Stem, Linker)
+            .with_transition(Stem, Derivation)
+            .with_transition(Stem, Inflection)
+            .with_transition(Derivation, Prefix)
+            .with_transition(Derivation, Stem)
+            .with_transition(Derivation, Linker)
+            .with_transition(Derivation, Derivation)
+            .with_transition(Derivation, Inflection)
+            .with_transition(Linker, Prefix)
+            .with_transition(Linker, Stem)
+            .with_final(Stem)
+            .with_final(Derivation)
+            .with_final(Inflection)
+    }
+
+    /// Allow words to start with a morpheme of `class`.
+    #[must_use]
+    pub fn with_initial(mut self, class: MorphemeClass) -> Self {
+        let _ = self.initial.insert(class);
+        self
+    }
+
+    /// Allow a morpheme of class `to` to follow one of class `from`.
+    #[must_use]
+    pub fn with_transition(mut self, from: MorphemeClass, to: MorphemeClass) -> Self {
+        let _ = self.transitions.insert((from, to));
+        self
+    }
+
+    /// Allow words to end with a morpheme of `class`.
+    #[must_use]
+    pub fn with_final(mut self, class: MorphemeClass) -> Self {
+        let _ = self.finals.insert(class);
+        self
+    }
+
+    /// Whether a word made up of morphemes of `classes`, in order, is allowed.
+    #[must_use]
+    pub fn allows(&self, classes: &[MorphemeClass]) -> bool {
+        let mut state = None;
+        for &class in classes {
+            if !self.may_follow(state, class) {
+                return false;
+            }
+            state = Some(class);
+        }
+
+        state.map_or(false, |class| self.finals.contains(&class))
+    }
+
+    /// Whether a morpheme of `class` may follow one of class `state`, or start the
+    /// word if `None`.
+    fn may_follow(&self, state: Option<MorphemeClass>, class: MorphemeClass) -> bool {
+        match state {
+            None => self.initial.contains(&class),
+            Some(from) => self.transitions.contains(&(from, class)),
+        }
+    }
+}
+
+/// A single morpheme of a word, as returned by [`decompound_morphemes`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Morpheme {
+    span: Range<usize>,
+    form: String,
+    class: MorphemeClass,
+}
+
+impl Morpheme {
+    /// Byte range of this morpheme in the word originally passed in, as for
+    /// [`Constituent::span`](crate::Constituent::span).
+    #[must_use]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// The form this morpheme was found valid as.
+    #[must_use]
+    pub fn form(&self) -> &str {
+        &self.form
+    }
+
+    /// The class this morpheme was found valid as.
+    #[must_use]
+    pub fn class(&self) -> MorphemeClass {
+        self.class
+    }
+}
+
+/// Segments `word` into morphemes, in a sequence of classes `grammar` allows, each
+/// morpheme valid for its class according to `is_valid_morpheme`. The lexicon is
+/// consulted once per distinct form and class.
+///
+/// Of all valid segmentations, the one with the fewest morphemes wins, or with the
+/// most for [`DecompositionOptions::SHATTER`]. Of `config`, only that,
+/// [`DecompositionOptions::TRY_TITLECASE_SUFFIX`] and
+/// [`DecompositionConfig::with_transform`], and
+/// [`DecompositionOptions::BOUNDARY_HINTS`] (hints are skipped) apply.
+///
+/// ```
+/// use decompound::{
+///     decompound_morphemes, DecompositionOptions, MorphemeClass, Morphotactics,
+/// };
+///
+/// let is_valid_morpheme = |m: &str, class| match class {
+///     MorphemeClass::Stem => ["Freund", "Spiel"].contains(&m),
+///     MorphemeClass::Derivation => ["schaft", "lich", "keit"].contains(&m),
+///     MorphemeClass::Linker => ["s", "es", "n"].contains(&m),
+///     _ => false,
+/// };
+///
+/// let morphemes = decompound_morphemes(
+///     "Freundschaftsspiel",
+///     &is_valid_morpheme,
+///     &Morphotactics::german(),
+///     DecompositionOptions::TRY_TITLECASE_SUFFIX,
+/// ).unwrap();
+///
+/// assert_eq!(
+///     morphemes.iter().map(|m| (m.form(), m.class())).collect::<Vec<_>>(),
+///     vec![
+///         ("Freund", MorphemeClass::Stem),
+///         ("schaft", MorphemeClass::Derivation),
+///         ("s", MorphemeClass::Linker),
+///         ("Spiel", MorphemeClass::Stem),
+///     ]
+/// );
+/// ```
+///
+/// ## Errors
+///
+/// [`DecompositionError::NothingValid`] if `grammar` allows no segmentation of the
+/// word, and [`DecompositionError::SingleWord`] if the best one is a single morpheme.
+pub fn decompound_morphemes<'a>(
+    word: impl AsRef<str>,
+    is_valid_morpheme: &impl Fn(&str, MorphemeClass) -> bool,
+    grammar: &Morphotactics,
+    config: impl Into<DecompositionConfig<'a>>,
+) -> Result<Vec<Morpheme>, DecompositionError> {
+    let word = word.as_ref();
+    let config = config.into();
+
+    let hinted = Hinted::new(word);
+    let stripped = if config.strips_boundary_hints() {
+        hinted.stripped()
+    } else {
+        word
+    };
+
+    let mut morphemes = segment(stripped, is_valid_morpheme, grammar, &config)
+        .ok_or(DecompositionError::NothingValid)?;
+
+    if config.strips_boundary_hints() {
+        for morpheme in &mut morphemes {
+            morpheme.span = hinted.restore(&morpheme.span);
+        }
+    }
+
+    match &morphemes[..] {
+        [morpheme] => Err(DecompositionError::SingleWord(morpheme.form.clone())),
+        _ => Ok(morphemes),
+    }
+}
+
+/// The best segmentation of `word` reaching a position in a state.
+type Reached = HashMap<(usize, Option<MorphemeClass>), Vec<Morpheme>>;
+
+fn segment(
+    word: &str,
+    is_valid_morpheme: &impl Fn(&str, MorphemeClass) -> bool,
+    grammar: &Morphotactics,
+    config: &DecompositionConfig<'_>,
+) -> Option<Vec<Morpheme>> {
+    let shatter = config.options.contains(DecompositionOptions::SHATTER);
+    let is_better = |candidate: &[Morpheme], current: Option<&Vec<Morpheme>>| {
+        current.map_or(true, |current| {
+            if shatter {
+                candidate.len() > current.len()
+            } else {
+                candidate.len() < current.len()
+            }
+        })
+    };
+
+    let mut validity: HashMap<(String, MorphemeClass), bool> = HashMap::new();
+    let mut is_valid = |form: &str, class: MorphemeClass| {
+        *validity
+            .entry((form.to_owned(), class))
+            .or_insert_with(|| is_valid_morpheme(form, class))
+    };
+
+    let boundaries: Vec<usize> = word
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(word.len()))
+        .collect();
+
+    let mut reached: Reached = HashMap::new();
+    let _ = reached.insert((0, None), Vec::new());
+
+    for (i, &start) in boundaries.iter().enumerate() {
+        // States in a fixed order, so ties are broken the same way every time.
+        let paths: Vec<(Option<MorphemeClass>, Vec<Morpheme>)> = states()
+            .filter_map(|state| Some((state, reached.get(&(start, state))?.clone())))
+            .collect();
+
+        for (state, path) in paths {
+            for &end in &boundaries[i + 1..] {
+                let span = start..end;
+                let forms =
+                    transform::forms(&word[span.clone()], Position::of(&span, word.len()), config);
+
+                for class in MorphemeClass::ALL {
+                    if !grammar.may_follow(state, class) {
+                        continue;
+                    }
+
+                    let form = match forms.iter().find(|form| is_valid(form, class)) {
+                        Some(form) => form,
+                        None => continue,
+                    };
+
+                    let mut candidate = path.clone();
+                    candidate.push(Morpheme {
+                        span: span.clone(),
+                        form: form.clone(),
+                        class,
+                    });
+
+                    let key = (end, Some(class));
+                    if is_better(&candidate, reached.get(&key)) {
+                        let _ = reached.insert(key, candidate);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut best: Option<Vec<Morpheme>> = None;
+    for class in MorphemeClass::ALL {
+        if !grammar.finals.contains(&class) {
+            continue;
+        }
+
+        if let Some(path) = reached.remove(&(word.len(), Some(class))) {
+            if is_better(&path, best.as_ref()) {
+                best = Some(path);
+            }
+        }
+    }
+
+    best
+}
+
+/// All states of the grammar, in a fixed order: the start, then all classes.
+fn states() -> impl Iterator<Item = Option<MorphemeClass>> {
+    std::iter::once(None).chain(MorphemeClass::ALL.into_iter().map(Some))
+}
@@ -0,0 +1,293 @@
+//! Hooks into the search, for profiling and debugging.
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashSet,
+    ops::Range,
+    time::{Duration, Instant},
+};
+
+use crate::Constituent;
+
+/// Something happening during the search for a decomposition, as passed to a
+/// [`SearchObserver`].
+///
+/// Byte ranges are relative to the word searched, which is the word passed in,
+/// stripped of boundary hints (see
+/// [`DecompositionOptions::BOUNDARY_HINTS`](crate::DecompositionOptions::BOUNDARY_HINTS)),
+/// or one of its hyphen-separated parts (see
+/// [`DecompositionOptions::SPLIT_HYPHENATED`](crate::DecompositionOptions::SPLIT_HYPHENATED)),
+/// as announced by [`SearchEvent::Started`]. Depth is the number of constituents
+/// preceding the part of the word currently searched.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum SearchEvent<'e> {
+    /// The search of `word` started.
+    Started {
+        /// The word searched.
+        word: &'e str,
+    },
+    /// The search of `word` finished.
+    Finished {
+        /// The word searched.
+        word: &'e str,
+    },
+    /// The validity check was called. Not every check of a constituent calls it, as
+    /// in case of [`DecompositionConfig::with_exceptions`](crate::DecompositionConfig::with_exceptions).
+    /// Lookups of the word as a whole, to tell apart single words from invalid ones,
+    /// might happen outside of [`SearchEvent::Started`] and [`SearchEvent::Finished`].
+    ValidatorCalled {
+        /// The word looked up.
+        form: &'e str,
+    },
+    /// A constituent was checked for validity.
+    CandidateChecked {
+        /// Where the constituent was found.
+        span: Range<usize>,
+        /// The form it was looked up as.
+        form: &'e str,
+        /// Whether `form` differs from the word's slice at `span`, for example as it
+        /// was titlecased.
+        transformed: bool,
+        /// Whether it was found valid.
+        valid: bool,
+    },
+    /// A constituent found invalid was checked for validity by its lemma (see
+    /// [`DecompositionConfig::with_lemmatizer`](crate::DecompositionConfig::with_lemmatizer)).
+    LemmaChecked {
+        /// Where the constituent was found.
+        span: Range<usize>,
+        /// The form it was found invalid as.
+        form: &'e str,
+        /// The lemma of `form`, as looked up.
+        lemma: &'e str,
+        /// Whether it was found valid.
+        valid: bool,
+    },
+    /// A prefix is about to be checked for validity.
+    PrefixTried {
+        /// Where the prefix was found.
+        span: Range<usize>,
+        /// The form it is looked up as.
+        form: &'e str,
+        /// See [`SearchEvent`].
+        depth: usize,
+    },
+    /// A prefix was found valid.
+    PrefixAccepted {
+        /// Where the prefix was found.
+        span: Range<usize>,
+        /// The form it was found valid as.
+        form: &'e str,
+        /// See [`SearchEvent`].
+        depth: usize,
+    },
+    /// A form to look up the rest of the word after a valid prefix as was generated.
+    SuffixCandidate {
+        /// Where the suffix was found.
+        span: Range<usize>,
+        /// The form it is going to be looked up as.
+        form: &'e str,
+        /// See [`SearchEvent`].
+        depth: usize,
+    },
+    /// A valid decomposition of the rest of the word was found.
+    SplitRecorded {
+        /// The decomposition.
+        constituents: &'e [Constituent],
+        /// See [`SearchEvent`].
+        depth: usize,
+    },
+    /// Of all valid decompositions of the rest of the word, this one was picked.
+    SplitSelected {
+        /// The decomposition.
+        constituents: &'e [Constituent],
+        /// See [`SearchEvent`].
+        depth: usize,
+    },
+}
+
+/// Gets notified of [`SearchEvent`]s, set via
+/// [`DecompositionConfig::with_observer`](crate::DecompositionConfig::with_observer).
+///
+/// Observers are shared, so keep any state behind interior mutability, like
+/// [`SearchStats`] does.
+pub trait SearchObserver {
+    /// Called for every event, in order.
+    fn on_event(&self, event: &SearchEvent<'_>);
+}
+
+/// A [`SearchObserver`] collecting statistics, accumulated over all searches it
+/// observes.
+///
+/// ```
+/// use decompound::{decompound, DecompositionConfig, DecompositionOptions, SearchStats};
+///
+/// let is_valid_single_word = |w: &str| ["Haus", "Boot"].contains(&w);
+/// let stats = SearchStats::new();
+///
+/// decompound(
+///     "Hausboot",
+///     &is_valid_single_word,
+///     DecompositionConfig::new(DecompositionOptions::TRY_TITLECASE_SUFFIX)
+///         .with_observer(&stats),
+/// ).unwrap();
+///
+/// assert_eq!(stats.candidate_splits(), 1);
+/// assert!(stats.validator_calls() >= stats.distinct_substrings());
+/// ```
+#[derive(Debug, Default)]
+pub struct SearchStats {
+    validator_calls: Cell<usize>,
+    substrings: RefCell<HashSet<String>>,
+    max_depth: Cell<usize>,
+    candidate_splits: Cell<usize>,
+    started: Cell<Option<Instant>>,
+    elapsed: Cell<Duration>,
+}
+
+impl SearchStats {
+    /// Creates statistics with all counts at zero.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of calls of the validity check.
+    #[must_use]
+    pub fn validator_calls(&self) -> usize {
+        self.validator_calls.get()
+    }
+
+    /// Number of distinct words the validity check was called with.
+    #[must_use]
+    pub fn distinct_substrings(&self) -> usize {
+        self.substrings.borrow().len()
+    }
+
+    /// Deepest level of recursion reached, see [`SearchEvent`].
+    #[must_use]
+    pub fn max_depth(&self) -> usize {
+        self.max_depth.get()
+    }
+
+    /// Number of valid decompositions found for the rest of the word at any level of
+    /// recursion, see [`SearchEvent::SplitRecorded`].
+    #[must_use]
+    pub fn candidate_splits(&self) -> usize {
+        self.candidate_splits.get()
+    }
+
+    /// Total time spent searching.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed.get()
+    }
+}
+
+impl SearchObserver for SearchStats {
+    fn on_event(&self, event: &SearchEvent<'_>) {
+        match event {
+            SearchEvent::Started { .. } => self.started.set(Some(Instant::now())),
+            SearchEvent::Finished { .. } => {
+                if let Some(started) = self.started.take() {
+                    self.elapsed.set(self.elapsed.get() + started.elapsed());
+                }
+            }
+            SearchEvent::ValidatorCalled { form } => {
+                self.validator_calls.set(self.validator_calls.get() + 1);
+
+                let mut substrings = self.substrings.borrow_mut();
+                if !substrings.contains(*form) {
+                    let _ = substrings.insert((*form).to_owned());
+                }
+            }
+            SearchEvent::PrefixTried { depth, .. }
+            | SearchEvent::PrefixAccepted { depth, .. }
+            | SearchEvent::SuffixCandidate { depth, .. }
+            | SearchEvent::SplitSelected { depth, .. } => {
+                self.max_depth.set(self.max_depth.get().max(*depth));
+            }
+            SearchEvent::SplitRecorded { depth, .. } => {
+                self.max_depth.set(self.max_depth.get().max(*depth));
+                self.candidate_splits.set(self.candidate_splits.get() + 1);
+            }
+            SearchEvent::CandidateChecked { .. } | SearchEvent::LemmaChecked { .. } => {}
+        }
+    }
+}
+
+/// A constituent the search checked for validity, see [`SearchEvent::CandidateChecked`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Candidate {
+    /// Byte range in the word searched.
+    pub(crate) span: Range<usize>,
+    pub(crate) form: String,
+    pub(crate) transformed: bool,
+    pub(crate) valid: bool,
+}
+
+/// Collects all candidates checked, by the search they were checked in, passing on all
+/// events to the observer configured, if any. Lets functions explaining a search
+/// observe the very search whose result they return.
+pub(crate) struct Recorder<'o> {
+    observer: Option<&'o dyn SearchObserver>,
+    searches: RefCell<Vec<Vec<Candidate>>>,
+}
+
+impl<'o> Recorder<'o> {
+    pub(crate) fn new(observer: Option<&'o dyn SearchObserver>) -> Self {
+        Self {
+            observer,
+            searches: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn record(&self, candidate: Candidate) {
+        if let Some(candidates) = self.searches.borrow_mut().last_mut() {
+            candidates.push(candidate);
+        }
+    }
+
+    /// Candidates checked, for each search started, in order. Whole words looked up
+    /// after their search count towards it.
+    pub(crate) fn into_searches(self) -> Vec<Vec<Candidate>> {
+        self.searches.into_inner()
+    }
+}
+
+impl SearchObserver for Recorder<'_> {
+    fn on_event(&self, event: &SearchEvent<'_>) {
+        match event {
+            SearchEvent::Started { .. } => self.searches.borrow_mut().push(Vec::new()),
+            SearchEvent::CandidateChecked {
+                span,
+                form,
+                transformed,
+                valid,
+            } => self.record(Candidate {
+                span: span.clone(),
+                form: (*form).to_owned(),
+                transformed: *transformed,
+                valid: *valid,
+            }),
+            // Recorded by the form, as that is what makes up the decomposition.
+            SearchEvent::LemmaChecked {
+                span,
+                form,
+                valid: true,
+                ..
+            } => self.record(Candidate {
+                span: span.clone(),
+                form: (*form).to_owned(),
+                transformed: true,
+                valid: true,
+            }),
+            _ => {}
+        }
+
+        if let Some(observer) = self.observer {
+            observer.on_event(event);
+        }
+    }
+}
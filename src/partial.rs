@@ -0,0 +1,471 @@
+//! Best-effort decomposition of words not entirely covered by the lexicon.
+
+use std::{collections::HashMap, ops::Range};
+
+use crate::{
+    budget::Budget, decompose_word, hints::Hinted, strategy::Dictated, Constituent,
+    DecompositionConfig, DecompositionError, DecompositionOptions, Search,
+};
+
+/// A constituent of a partial decomposition, as returned by [`decompound_partial`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PartialConstituent {
+    /// A constituent found valid.
+    Known(Constituent),
+    /// A stretch of the word not covered by any valid constituent. Its form is the
+    /// stretch as spelled in the word.
+    Unknown(Constituent),
+}
+
+impl PartialConstituent {
+    /// Whether this constituent was found valid.
+    #[must_use]
+    pub fn is_known(&self) -> bool {
+        matches!(self, PartialConstituent::Known(_))
+    }
+
+    /// The underlying constituent, known or not.
+    #[must_use]
+    pub fn constituent(&self) -> &Constituent {
+        match self {
+            PartialConstituent::Known(c) | PartialConstituent::Unknown(c) => c,
+        }
+    }
+
+    /// Shorthand for [`Constituent::form`] of [`PartialConstituent::constituent`].
+    #[must_use]
+    pub fn form(&self) -> &str {
+        self.constituent().form()
+    }
+}
+
+/// Like [`decompound_spans`](crate::decompound_spans), but falls back to a best-effort
+/// decomposition if there is no complete one: the one covering most of the word with
+/// valid constituents, and of those, the one with the fewest parts. Stretches not
+/// covered are returned as [`PartialConstituent::Unknown`].
+///
+/// ```
+/// use decompound::{decompound_partial, DecompositionOptions, PartialConstituent};
+///
+/// // Missing "Kryptografie"
+/// let is_valid_single_word = |w: &str| ["Quanten", "Gerät"].contains(&w);
+///
+/// let word = "Quantenkryptografiegerät";
+/// let parts = decompound_partial(
+///     word,
+///     &is_valid_single_word,
+///     DecompositionOptions::TRY_TITLECASE_SUFFIX,
+/// ).unwrap();
+///
+/// assert_eq!(
+///     parts.iter().map(|p| (p.is_known(), p.form())).collect::<Vec<_>>(),
+///     vec![(true, "Quanten"), (false, "kryptografie"), (true, "Gerät")]
+/// );
+/// ```
+///
+/// A word which is valid as a single word is returned as its only known constituent.
+/// Known constituents are found and split between as for a complete decomposition,
+/// honoring for example [`DecompositionConfig::with_min_constituent_len`],
+/// [`DecompositionConfig::with_exceptions`] and split restrictions like
+/// [`DecompositionConfig::with_hyphenation_patterns`]. Unknown stretches, however, may
+/// start and end anywhere.
+///
+/// ## Errors
+///
+/// Only [`DecompositionError::BudgetExhausted`]: as long as the search is not given
+/// up on, there is always a partial decomposition, if only a single unknown stretch.
+/// Limits like [`DecompositionConfig::with_max_validator_calls`] are on the call as a
+/// whole, the search for a complete decomposition tried first included.
+pub fn decompound_partial<'a>(
+    word: impl AsRef<str>,
+    is_valid_single_word: &impl Fn(&str) -> bool,
+    config: impl Into<DecompositionConfig<'a>>,
+) -> Result<Vec<PartialConstituent>, DecompositionError> {
+    let word = word.as_ref();
+    let config = config.into();
+
+    // One budget for both the complete and the partial search.
+    let budget = Budget::new(&config);
+    let hinted = Hinted::new(word);
+    let strips_hints = config.strips_boundary_hints();
+    let stripped = if strips_hints {
+        hinted.stripped()
+    } else {
+        word
+    };
+    let hints: &[usize] = if strips_hints { hinted.hints() } else { &[] };
+    let restore = |span: &Range<usize>| {
+        if strips_hints {
+            hinted.restore(span)
+        } else {
+            span.clone()
+        }
+    };
+
+    match decompose_word(stripped, hints, is_valid_single_word, &config, &budget) {
+        Ok(ranked) => {
+            return Ok(ranked
+                .best
+                .into_iter()
+                .map(|mut c| {
+                    c.span = restore(&c.span);
+                    PartialConstituent::Known(c)
+                })
+                .collect())
+        }
+        Err(DecompositionError::SingleWord(form)) => {
+            return Ok(vec![PartialConstituent::Known(Constituent {
+                span: 0..word.len(),
+                form,
+                lemma: None,
+                lexicon: None,
+            })])
+        }
+        Err(e @ DecompositionError::BudgetExhausted(_)) => return Err(e),
+        Err(DecompositionError::NothingValid) => {}
+    }
+
+    let subwords: Vec<&str> = if config
+        .options
+        .contains(DecompositionOptions::SPLIT_HYPHENATED)
+    {
+        stripped.split('-').collect()
+    } else {
+        vec![stripped]
+    };
+
+    let mut parts = Vec::new();
+    let mut offset = 0;
+    for subword in subwords {
+        let subhints: Vec<usize> = hints
+            .iter()
+            .filter(|&&h| h >= offset && h <= offset + subword.len())
+            .map(|h| h - offset)
+            .collect();
+
+        let search = Search {
+            word: subword,
+            hints: &subhints,
+            split_points: config
+                .hyphenation_patterns
+                .map(|patterns| patterns.hyphenation_points(subword)),
+            is_valid_single_word,
+            config: &config,
+            budget: &budget,
+        };
+
+        for part in cover(&search) {
+            let shift = |mut c: Constituent| {
+                let span = (c.span.start + offset)..(c.span.end + offset);
+                c.span = restore(&span);
+                c
+            };
+
+            parts.push(match part {
+                PartialConstituent::Known(c) => PartialConstituent::Known(shift(c)),
+                PartialConstituent::Unknown(c) => {
+                    let c = shift(c);
+                    // Spelled as in the original word, including any hints within.
+                    PartialConstituent::Unknown(Constituent {
+                        form: word[c.span.clone()].to_owned(),
+                        span: c.span,
+                        lemma: None,
+                        lexicon: None,
+                    })
+                }
+            });
+        }
+
+        offset += subword.len() + '-'.len_utf8();
+    }
+
+    if budget.is_exhausted() {
+        return Err(DecompositionError::BudgetExhausted(None));
+    }
+
+    Ok(parts)
+}
+
+/// The last part of a partial decomposition up to some boundary, by indices into the
+/// word's boundaries.
+#[derive(Debug, Clone, Copy)]
+enum Last {
+    /// Nothing yet, at the start of the word.
+    Start,
+    /// An unknown char, ending at this boundary.
+    Unknown(usize),
+    /// A known constituent, spanning these boundaries.
+    Known(usize, usize),
+    /// The fixed split of the rest of the word (see [`Exceptions`]).
+    ///
+    /// [`Exceptions`]: crate::Exceptions
+    Fixed,
+}
+
+impl Last {
+    /// Whether this is known, which the start counts as, so that an unknown stretch
+    /// right at the start counts as a part.
+    fn is_known(self) -> bool {
+        !matches!(self, Last::Unknown(_))
+    }
+}
+
+/// How a boundary in the word was reached, in a partial decomposition.
+#[derive(Debug, Clone, Copy)]
+struct Step {
+    /// Chars covered by known constituents so far.
+    covered: usize,
+    /// Parts so far, with consecutive unknown chars counting as one.
+    parts: usize,
+    /// The part before the last one.
+    from: Last,
+}
+
+impl Step {
+    fn is_better_than(&self, other: Option<&Step>) -> bool {
+        other.map_or(true, |other| {
+            self.covered > other.covered
+                || (self.covered == other.covered && self.parts < other.parts)
+        })
+    }
+}
+
+/// Keeps `value`, reached by `step`, in `slot` if better than what is there.
+fn improve<T>(slot: &mut Option<(T, Step)>, value: impl FnOnce() -> T, step: Step) {
+    if step.is_better_than(slot.as_ref().map(|(_, step)| step)) {
+        *slot = Some((value(), step));
+    }
+}
+
+/// Keeps `step` in `slot` if better than what is there.
+fn improve_unknown(slot: &mut Option<Step>, step: Step) {
+    if step.is_better_than(slot.as_ref()) {
+        *slot = Some(step);
+    }
+}
+
+/// The partial decomposition of the search's word covering most of it. Known
+/// constituents are split between as the search would, but unknown stretches may
+/// start and end anywhere.
+fn cover<F: Fn(&str) -> bool>(search: &Search<'_, '_, F>) -> Vec<PartialConstituent> {
+    let mut cover = Cover::new(search.word);
+    let last = cover.boundaries.len() - 1;
+
+    for i in 0..last {
+        let mut reached: Vec<(Last, Step)> = Vec::new();
+        if i == 0 {
+            reached.push((
+                Last::Start,
+                Step {
+                    covered: 0,
+                    parts: 0,
+                    from: Last::Start,
+                },
+            ));
+        }
+        reached.extend((0..i).filter_map(|j| {
+            cover.known[i][j]
+                .as_ref()
+                .map(|(_, step)| (Last::Known(j, i), *step))
+        }));
+        reached.extend(cover.unknown[i].map(|step| (Last::Unknown(i), step)));
+
+        // Only looked up once a constituent ends here, as the search does.
+        let dictated = if reached.iter().any(|(l, _)| matches!(l, Last::Known(..))) {
+            search.dictated(cover.boundaries[i])
+        } else {
+            None
+        };
+
+        for (state, step) in reached {
+            cover.extend(search, i, state, step, dictated.as_ref());
+        }
+    }
+
+    cover.into_parts(search.word)
+}
+
+/// Best ways to reach each boundary of a word (by index into `boundaries`) in a
+/// partial decomposition.
+struct Cover {
+    boundaries: Vec<usize>,
+    /// Ending in an unknown char.
+    unknown: Vec<Option<Step>>,
+    /// Ending in a known constituent, by the boundary it starts at.
+    known: Vec<Vec<Option<(Constituent, Step)>>>,
+    /// Ending in a fixed split of the rest of the word.
+    fixed: Option<(Vec<Constituent>, Step)>,
+    /// Each span (by boundaries) as a constituent, if valid, looked up once.
+    valid: HashMap<(usize, usize), Option<Constituent>>,
+}
+
+impl Cover {
+    fn new(word: &str) -> Self {
+        let boundaries: Vec<usize> = word
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(word.len()))
+            .collect();
+        let n = boundaries.len();
+
+        Self {
+            boundaries,
+            unknown: vec![None; n],
+            known: vec![vec![None; n]; n],
+            fixed: None,
+            valid: HashMap::new(),
+        }
+    }
+
+    /// Continues from `state`, reached by `step` at boundary `i`, in all ways allowed.
+    fn extend<F: Fn(&str) -> bool>(
+        &mut self,
+        search: &Search<'_, '_, F>,
+        i: usize,
+        state: Last,
+        step: Step,
+        dictated: Option<&Dictated>,
+    ) {
+        let last = self.boundaries.len() - 1;
+        let next = |chars: usize, parts: usize| Step {
+            covered: step.covered + chars,
+            parts: step.parts + parts,
+            from: state,
+        };
+
+        if let Last::Known(j, _) = state {
+            if !search.may_split_at(self.boundaries[j], self.boundaries[i]) {
+                // Unknown stretches may follow anywhere.
+                improve_unknown(&mut self.unknown[i + 1], next(0, 1));
+                return;
+            }
+
+            match dictated {
+                Some(Dictated::Fixed(constituents)) => {
+                    let step = next(last - i, constituents.len());
+                    improve(&mut self.fixed, || constituents.clone(), step);
+                    return;
+                }
+                Some(Dictated::Whole) => {
+                    if let Some(constituent) = self.constituent(search, i, last) {
+                        improve(&mut self.known[last][i], || constituent, next(last - i, 1));
+                    }
+                    return;
+                }
+                None => {}
+            }
+        }
+
+        improve_unknown(
+            &mut self.unknown[i + 1],
+            next(0, usize::from(state.is_known())),
+        );
+
+        for k in (i + 1)..=last {
+            if k - i < search.config.min_constituent_len {
+                continue;
+            }
+
+            if let Some(constituent) = self.constituent(search, i, k) {
+                improve(&mut self.known[k][i], || constituent, next(k - i, 1));
+            }
+        }
+    }
+
+    /// The span between boundaries `i` and `k` as a constituent, if valid.
+    fn constituent<F: Fn(&str) -> bool>(
+        &mut self,
+        search: &Search<'_, '_, F>,
+        i: usize,
+        k: usize,
+    ) -> Option<Constituent> {
+        let span = self.boundaries[i]..self.boundaries[k];
+
+        self.valid
+            .entry((i, k))
+            .or_insert_with(|| search.constituent(span))
+            .clone()
+    }
+
+    /// How the end of the word is best reached. Of equally good ways, known
+    /// constituents win over fixed splits over unknown stretches.
+    fn end(&self) -> Option<Last> {
+        let last = self.boundaries.len() - 1;
+
+        let known = self.known[last]
+            .iter()
+            .enumerate()
+            .filter_map(|(j, reached)| {
+                reached
+                    .as_ref()
+                    .map(|(_, step)| (Last::Known(j, last), *step))
+            });
+        let fixed = self.fixed.as_ref().map(|(_, step)| (Last::Fixed, *step));
+        let unknown = self.unknown[last].map(|step| (Last::Unknown(last), step));
+
+        let mut end: Option<(Last, Step)> = None;
+        for (state, step) in known.chain(fixed).chain(unknown) {
+            if step.is_better_than(end.as_ref().map(|(_, step)| step)) {
+                end = Some((state, step));
+            }
+        }
+
+        end.map(|(state, _)| state)
+    }
+
+    /// Walks back from the end, merging consecutive unknown chars.
+    fn into_parts(mut self, word: &str) -> Vec<PartialConstituent> {
+        let mut parts: Vec<PartialConstituent> = Vec::new();
+        let mut state = self.end().unwrap_or(Last::Start);
+        let mut unknown_end = None;
+
+        loop {
+            state = match state {
+                Last::Start => break,
+                Last::Unknown(i) => {
+                    let step = self.unknown[i].expect("Unknown char must have been reached");
+                    let end = *unknown_end.get_or_insert(self.boundaries[i]);
+
+                    if !matches!(step.from, Last::Unknown(_)) {
+                        unknown_end = None;
+                        parts.push(unknown_part(word, self.boundaries[i - 1]..end));
+                    }
+                    step.from
+                }
+                Last::Known(j, i) => {
+                    let (constituent, step) = self.known[i][j]
+                        .take()
+                        .expect("Known constituent must have been reached");
+                    parts.push(PartialConstituent::Known(constituent));
+                    step.from
+                }
+                Last::Fixed => {
+                    let (constituents, step) = self
+                        .fixed
+                        .take()
+                        .expect("Fixed split must have been reached");
+                    parts.extend(
+                        constituents
+                            .into_iter()
+                            .rev()
+                            .map(PartialConstituent::Known),
+                    );
+                    step.from
+                }
+            };
+        }
+        parts.reverse();
+
+        parts
+    }
+}
+
+/// An unknown stretch of `word`.
+fn unknown_part(word: &str, span: Range<usize>) -> PartialConstituent {
+    PartialConstituent::Unknown(Constituent {
+        form: word[span.clone()].to_owned(),
+        span,
+        lemma: None,
+        lexicon: None,
+    })
+}
@@ -0,0 +1,313 @@
+//! Strategies to search for a decomposition with, trading accuracy for speed.
+
+use std::ops::Range;
+
+use log::trace;
+
+use crate::{Constituent, Ranked, Search, SearchEvent};
+
+/// How [`decompound`](crate::decompound) searches for a decomposition, set via
+/// [`DecompositionConfig::with_strategy`](crate::DecompositionConfig::with_strategy).
+///
+/// All strategies but [`SearchStrategy::Exhaustive`] look up each constituent in the
+/// first form found valid (as is, then transformed, see
+/// [`DecompositionConfig::with_transform`](crate::DecompositionConfig::with_transform)),
+/// and only prefer splits at boundary hints among the decompositions they come across
+/// anyway.
+///
+/// ```
+/// use decompound::{decompound, DecompositionConfig, DecompositionOptions, SearchStrategy};
+///
+/// let is_valid_single_word = |w: &str| [
+///     "Staub",
+///     "Sauger",
+///     "Staubs",
+///     "Auger",
+/// ].contains(&w);
+///
+/// let config = DecompositionConfig::new(DecompositionOptions::TRY_TITLECASE_SUFFIX);
+///
+/// assert_eq!(
+///     decompound(
+///         "Staubsauger",
+///         &is_valid_single_word,
+///         config.clone().with_strategy(SearchStrategy::GreedyLongestPrefix),
+///     ).unwrap(),
+///     vec!["Staubs", "Auger"]
+/// );
+/// assert_eq!(
+///     decompound(
+///         "Staubsauger",
+///         &is_valid_single_word,
+///         config.with_strategy(SearchStrategy::GreedyLongestSuffix),
+///     ).unwrap(),
+///     vec!["Staub", "Sauger"]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SearchStrategy {
+    /// Try all possible splits, then pick the best one. Finds a decomposition whenever
+    /// there is one, but takes time exponential in the word's length in the worst case.
+    #[default]
+    Exhaustive,
+    /// From the left, repeatedly take the longest valid constituent, never
+    /// backtracking. Fails if that leaves a rest which cannot be decomposed.
+    GreedyLongestPrefix,
+    /// From the right, repeatedly take the longest valid constituent, never
+    /// backtracking. As heads of compounds are on the right in Germanic languages,
+    /// this finds the (longest) head first.
+    GreedyLongestSuffix,
+    /// From the left, extend each of at most `width` partial decompositions by every
+    /// valid next constituent, keeping those covering most of the word for the next
+    /// round. The best of all complete decompositions found is picked, as for
+    /// [`SearchStrategy::Exhaustive`]. A `width` of zero is taken as one.
+    Beam {
+        /// Number of partial decompositions kept per round.
+        width: usize,
+    },
+}
+
+/// How [`Exceptions`](crate::Exceptions) dictate decomposing the rest of a word.
+pub(crate) enum Dictated {
+    /// Exactly into these constituents.
+    Fixed(Vec<Constituent>),
+    /// Not at all.
+    Whole,
+}
+
+impl<F: Fn(&str) -> bool> Search<'_, '_, F> {
+    /// Searches for decompositions of the entire word according to the configured
+    /// strategy, ranking all found.
+    pub(crate) fn run(&self) -> Option<Ranked> {
+        self.observe(&SearchEvent::Started { word: self.word });
+
+        let mut candidates = match self.config.strategy {
+            SearchStrategy::Exhaustive => self.valid_splits(0, 0),
+            SearchStrategy::GreedyLongestPrefix => {
+                self.greedy_longest_prefix().into_iter().collect()
+            }
+            SearchStrategy::GreedyLongestSuffix => {
+                self.greedy_longest_suffix().into_iter().collect()
+            }
+            SearchStrategy::Beam { width } => self.beam(width.max(1)),
+        };
+
+        let max_len = self.max_len();
+        candidates.retain(|found| found.len() >= 2 && found.len() <= max_len);
+
+        let ranked = self.rank(&candidates);
+        if let Some(ranked) = &ranked {
+            self.observe(&SearchEvent::SplitSelected {
+                constituents: &ranked.best,
+                depth: 0,
+            });
+        }
+
+        self.observe(&SearchEvent::Finished { word: self.word });
+        ranked
+    }
+
+    /// Maximum number of constituents, considering both the limit on them and on depth.
+    fn max_len(&self) -> usize {
+        let by_depth = self
+            .config
+            .max_depth
+            .map_or(usize::MAX, |depth| depth.saturating_add(2));
+
+        self.config
+            .max_constituents
+            .unwrap_or(usize::MAX)
+            .min(by_depth)
+    }
+
+    /// The first valid form of `word[span]`, as a constituent.
+    pub(crate) fn constituent(&self, span: Range<usize>) -> Option<Constituent> {
+        self.forms(span.clone())
+            .into_iter()
+            .find_map(|form| self.valid_constituent(span.clone(), form))
+    }
+
+    /// What exceptions say about decomposing `word[start..]`, if anything.
+    pub(crate) fn dictated(&self, start: usize) -> Option<Dictated> {
+        let exceptions = self.config.exceptions?;
+
+        self.forms(start..self.word.len())
+            .into_iter()
+            .find_map(|form| {
+                if let Some(fixed) = self.fixed_split(start, &form) {
+                    trace!("Using fixed split of '{form}'");
+                    Some(Dictated::Fixed(fixed))
+                } else if exceptions.is_undecomposable(&form) {
+                    trace!("'{form}' is never decomposed");
+                    Some(Dictated::Whole)
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// Char boundaries of the word strictly after `start`, including its end.
+    pub(crate) fn ends_after(&self, start: usize) -> impl DoubleEndedIterator<Item = usize> + '_ {
+        self.word[start..]
+            .char_indices()
+            .filter(|&(i, _)| i > 0)
+            .map(move |(i, _)| start + i)
+            .chain(std::iter::once(self.word.len()))
+    }
+
+    fn greedy_longest_prefix(&self) -> Option<Vec<Constituent>> {
+        let len = self.word.len();
+        let mut constituents = Vec::new();
+        let mut start = 0;
+
+        while start < len {
+            match self.dictated(start) {
+                Some(Dictated::Fixed(fixed)) => {
+                    constituents.extend(fixed);
+                    return Some(constituents);
+                }
+                Some(Dictated::Whole) if start > 0 => {
+                    constituents.push(self.constituent(start..len)?);
+                    return Some(constituents);
+                }
+                Some(Dictated::Whole) => return None,
+                None => {}
+            }
+
+            let constituent = self
+                .ends_after(start)
+                .rev()
+                // The whole word is no decomposition.
+                .filter(|&end| start > 0 || end < len)
+                .filter(|&end| end == len || self.may_split_at(start, end))
+                .find_map(|end| self.constituent(start..end))?;
+
+            trace!("Longest valid prefix: '{}'", constituent.form);
+            start = constituent.span.end;
+            constituents.push(constituent);
+        }
+
+        Some(constituents)
+    }
+
+    fn greedy_longest_suffix(&self) -> Option<Vec<Constituent>> {
+        let len = self.word.len();
+
+        match self.dictated(0) {
+            Some(Dictated::Fixed(fixed)) => return Some(fixed),
+            Some(Dictated::Whole) => return None,
+            None => {}
+        }
+
+        // Collected right to left.
+        let mut constituents = Vec::new();
+        let mut end = len;
+
+        while end > 0 {
+            let starts = self.word[..end].char_indices().map(|(i, _)| i);
+
+            let mut found = None;
+            for start in starts.filter(|&start| start > 0 || end < len) {
+                if end == len {
+                    match self.dictated(start) {
+                        Some(Dictated::Fixed(fixed)) => {
+                            found = Some(fixed);
+                            break;
+                        }
+                        Some(Dictated::Whole) => {
+                            found = self.constituent(start..end).map(|c| vec![c]);
+                            break;
+                        }
+                        None => {}
+                    }
+                }
+
+                if end < len && !self.may_split_at(start, end) {
+                    continue;
+                }
+
+                if let Some(constituent) = self.constituent(start..end) {
+                    found = Some(vec![constituent]);
+                    break;
+                }
+            }
+
+            let found = found?;
+            trace!("Longest valid suffix: {found:?}");
+
+            end = found.first().map_or(0, |c| c.span.start);
+            constituents.extend(found.into_iter().rev());
+        }
+
+        constituents.reverse();
+        Some(constituents)
+    }
+
+    /// All complete decompositions found, in the order the exhaustive search would
+    /// find them.
+    fn beam(&self, width: usize) -> Vec<Vec<Constituent>> {
+        let len = self.word.len();
+        let max_len = self.max_len();
+
+        let mut complete: Vec<Vec<Constituent>> = Vec::new();
+        let mut partial: Vec<Vec<Constituent>> = vec![Vec::new()];
+
+        while !partial.is_empty() {
+            let mut extended = Vec::new();
+
+            for constituents in partial {
+                let start = constituents.last().map_or(0, |c| c.span.end);
+                let extend = |further: Vec<Constituent>| {
+                    let mut constituents = constituents.clone();
+                    constituents.extend(further);
+                    constituents
+                };
+
+                match self.dictated(start) {
+                    Some(Dictated::Fixed(fixed)) => {
+                        complete.push(extend(fixed));
+                        continue;
+                    }
+                    Some(Dictated::Whole) => {
+                        if let Some(c) = self.constituent(start..len).filter(|_| start > 0) {
+                            complete.push(extend(vec![c]));
+                        }
+                        continue;
+                    }
+                    None => {}
+                }
+
+                for end in self.ends_after(start) {
+                    if end == len {
+                        if let Some(c) = self.constituent(start..end).filter(|_| start > 0) {
+                            complete.push(extend(vec![c]));
+                        }
+                    } else if constituents.len() + 2 <= max_len && self.may_split_at(start, end) {
+                        if let Some(c) = self.constituent(start..end) {
+                            extended.push(extend(vec![c]));
+                        }
+                    }
+                }
+            }
+
+            // Stable, so earlier (shorter) prefixes win ties.
+            extended.sort_by_key(|constituents| {
+                std::cmp::Reverse(constituents.last().map_or(0, |c| c.span.end))
+            });
+            extended.truncate(width);
+            trace!("Beam: {extended:?}");
+
+            partial = extended;
+        }
+
+        // Break ties the way the exhaustive search does, which tries shorter prefixes
+        // first.
+        complete.sort_by(|a, b| {
+            let ends = |constituents: &Vec<Constituent>| {
+                constituents.iter().map(|c| c.span.end).collect::<Vec<_>>()
+            };
+            ends(a).cmp(&ends(b))
+        });
+        complete
+    }
+}
@@ -0,0 +1,103 @@
+//! Alternative lexical forms to look up constituents as.
+
+use std::{borrow::Cow, iter, ops::Range};
+
+use unicode_titlecase::StrTitleCase;
+
+use crate::{DecompositionConfig, DecompositionOptions, GenderMarker};
+
+/// Where a constituent sits in the word it was found in, as passed to transforms (see
+/// [`DecompositionConfig::with_transform`]). With
+/// [`DecompositionOptions::SPLIT_HYPHENATED`], each hyphen-separated part counts as a
+/// word of its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Position {
+    /// At the start of the word.
+    Initial,
+    /// Neither at the start nor the end of the word.
+    Medial,
+    /// At the end of the word.
+    Final,
+}
+
+impl Position {
+    /// The position of a constituent at `span` in a word of `len` bytes.
+    pub(crate) fn of(span: &Range<usize>, len: usize) -> Self {
+        if span.start == 0 {
+            Position::Initial
+        } else if span.end == len {
+            Position::Final
+        } else {
+            Position::Medial
+        }
+    }
+}
+
+/// Forms produced by a transform, see [`DecompositionConfig::with_transform`].
+pub type Forms<'s> = Box<dyn Iterator<Item = Cow<'s, str>> + 's>;
+
+/// The transform behind [`DecompositionOptions::TRY_TITLECASE_SUFFIX`]: titlecases
+/// all constituents but the initial one.
+///
+/// ```
+/// use decompound::{titlecase, Position};
+///
+/// assert_eq!(titlecase("boot", Position::Final).collect::<Vec<_>>(), vec!["Boot"]);
+/// assert_eq!(titlecase("BOOT", Position::Medial).collect::<Vec<_>>(), vec!["Boot"]);
+/// assert_eq!(titlecase("haus", Position::Initial).count(), 0);
+/// ```
+#[must_use]
+pub fn titlecase(surface: &str, position: Position) -> Forms<'_> {
+    if position == Position::Initial {
+        return Box::new(iter::empty());
+    }
+
+    Box::new(iter::once(Cow::Owned(surface.to_titlecase_lower_rest())))
+}
+
+/// All forms to look up `surface` at `position` as, in order of preference: as is,
+/// then as produced by the built-in and configured transforms, without duplicates or
+/// empty ones.
+///
+/// For gender-inclusive forms (see [`DecompositionOptions::GENDER_INCLUSIVE`]), only
+/// the base is transformed, keeping marker and feminine suffix as found: titlecasing
+/// `lehrerInnen` as a whole would lose its Binnen-I.
+pub(crate) fn forms(
+    surface: &str,
+    position: Position,
+    config: &DecompositionConfig<'_>,
+) -> Vec<String> {
+    if config
+        .options
+        .contains(DecompositionOptions::GENDER_INCLUSIVE)
+    {
+        if let Some((base, marker, suffix)) = GenderMarker::split(surface) {
+            let marker = marker.as_char().map(String::from).unwrap_or_default();
+
+            return forms(base, position, config)
+                .into_iter()
+                .map(|base| format!("{base}{marker}{suffix}"))
+                .collect();
+        }
+    }
+
+    let mut forms = vec![surface.to_owned()];
+
+    let mut transforms = config.transforms.clone();
+    if config
+        .options
+        .contains(DecompositionOptions::TRY_TITLECASE_SUFFIX)
+    {
+        transforms.insert(0, &titlecase);
+    }
+
+    for transform in transforms {
+        for form in transform(surface, position) {
+            if !form.is_empty() && !forms.iter().any(|f| *f == form) {
+                forms.push(form.into_owned());
+            }
+        }
+    }
+
+    forms
+}
@@ -0,0 +1,379 @@
+//! Decompositions as binary trees, bracketing constituents which belong together.
+
+use std::{fmt, ops::Range};
+
+use log::trace;
+
+use crate::{
+    budget::Budget, decompose_word, hints::Hinted, Constituent, DecompositionConfig,
+    DecompositionError, Headedness, Search,
+};
+
+/// How [`decompound_tree`] brackets constituents, set via
+/// [`DecompositionConfig::with_bracketing`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Bracketing {
+    /// As the search nests them: each constituent modifies the compound made up of
+    /// all constituents after it.
+    #[default]
+    Search,
+    /// By lexicon evidence: constituents which are compounds themselves (like
+    /// *Dampfschiff*) are decomposed further, and brackets are placed such that as
+    /// many of the compounds they form as possible are valid words. Of equally
+    /// supported trees, the one splitting off the head (see
+    /// [`DecompositionConfig::with_headedness`]) earliest wins.
+    LexiconEvidence,
+}
+
+/// A decomposition as a binary tree, as returned by [`decompound_tree`].
+///
+/// Displays in bracket notation, with the outermost brackets left off:
+///
+/// ```
+/// use decompound::{decompound_tree, Bracketing, DecompositionConfig, DecompositionOptions};
+///
+/// let is_valid_single_word = |w: &str| [
+///     "Donau",
+///     "Dampf",
+///     "Schiff",
+///     "Fahrt",
+///     "Gesellschaft",
+///     "Dampfschiff",
+///     "Donaudampfschiff",
+///     "Donaudampfschifffahrt",
+/// ].contains(&w);
+///
+/// let tree = decompound_tree(
+///     "Donaudampfschifffahrtgesellschaft",
+///     &is_valid_single_word,
+///     DecompositionConfig::new(DecompositionOptions::TRY_TITLECASE_SUFFIX)
+///         .with_bracketing(Bracketing::LexiconEvidence),
+/// ).unwrap();
+///
+/// assert_eq!(
+///     tree.to_string(),
+///     "((Donau (Dampf Schiff)) Fahrt) Gesellschaft"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CompoundTree {
+    /// A single constituent.
+    Leaf(Constituent),
+    /// A compound of two parts.
+    Node(Box<CompoundTree>, Box<CompoundTree>),
+}
+
+impl CompoundTree {
+    /// All constituents, in order of appearance in the word.
+    #[must_use]
+    pub fn leaves(&self) -> Vec<&Constituent> {
+        match self {
+            CompoundTree::Leaf(constituent) => vec![constituent],
+            CompoundTree::Node(left, right) => {
+                let mut leaves = left.leaves();
+                leaves.extend(right.leaves());
+                leaves
+            }
+        }
+    }
+
+    /// Byte range covered in the word originally passed in.
+    #[must_use]
+    pub fn span(&self) -> Range<usize> {
+        match self {
+            CompoundTree::Leaf(constituent) => constituent.span(),
+            CompoundTree::Node(left, right) => left.span().start..right.span().end,
+        }
+    }
+
+    fn node(left: CompoundTree, right: CompoundTree) -> Self {
+        CompoundTree::Node(Box::new(left), Box::new(right))
+    }
+
+    /// Nests `trees` to the right, as the search does.
+    fn nested(trees: Vec<CompoundTree>) -> Option<Self> {
+        trees
+            .into_iter()
+            .rev()
+            .reduce(|right, left| Self::node(left, right))
+    }
+
+    /// This tree with the spans of all its constituents mapped by `f`.
+    fn map_spans(self, f: &impl Fn(&Range<usize>) -> Range<usize>) -> Self {
+        match self {
+            CompoundTree::Leaf(mut constituent) => {
+                constituent.span = f(&constituent.span);
+                CompoundTree::Leaf(constituent)
+            }
+            CompoundTree::Node(left, right) => {
+                CompoundTree::node(left.map_spans(f), right.map_spans(f))
+            }
+        }
+    }
+}
+
+impl fmt::Display for CompoundTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompoundTree::Leaf(constituent) => write!(f, "{}", constituent.form()),
+            CompoundTree::Node(left, right) => {
+                for (i, child) in [left, right].into_iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                    }
+
+                    match **child {
+                        CompoundTree::Leaf(_) => write!(f, "{child}")?,
+                        CompoundTree::Node(..) => write!(f, "({child})")?,
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Like [`decompound_spans`](crate::decompound_spans), but returns the decomposition
+/// as a tree, bracketed according to [`DecompositionConfig::with_bracketing`]. Parts
+/// of words split on hyphens (see
+/// [`DecompositionOptions::SPLIT_HYPHENATED`](crate::DecompositionOptions::SPLIT_HYPHENATED))
+/// are bracketed on their own, then with each other.
+///
+/// ```
+/// use decompound::{decompound_tree, DecompositionOptions};
+///
+/// let is_valid_single_word = |w: &str| ["Donau", "Dampf", "Schiff"].contains(&w);
+///
+/// let tree = decompound_tree(
+///     "Donaudampfschiff",
+///     &is_valid_single_word,
+///     DecompositionOptions::TRY_TITLECASE_SUFFIX,
+/// ).unwrap();
+///
+/// assert_eq!(tree.to_string(), "Donau (Dampf Schiff)");
+/// ```
+///
+/// ## Errors
+///
+/// Same as [`decompound`](crate::decompound). Lexicon evidence is looked up within the
+/// same budget as the decomposition, so [`DecompositionError::BudgetExhausted`] is
+/// returned if it runs out while bracketing, too.
+#[allow(clippy::missing_panics_doc)] // Decompositions have at least two constituents.
+pub fn decompound_tree<'a>(
+    word: impl AsRef<str>,
+    is_valid_single_word: &impl Fn(&str) -> bool,
+    config: impl Into<DecompositionConfig<'a>>,
+) -> Result<CompoundTree, DecompositionError> {
+    let word = word.as_ref();
+    let config = config.into();
+
+    let budget = Budget::new(&config);
+    let hinted = Hinted::new(word);
+    let strips_hints = config.strips_boundary_hints();
+    let (stripped, hints) = if strips_hints {
+        (hinted.stripped(), hinted.hints())
+    } else {
+        (word, &[][..])
+    };
+
+    let bracketer = Bracketer {
+        is_valid_single_word,
+        config: &config,
+        budget: &budget,
+    };
+    let constituents =
+        decompose_word(stripped, hints, is_valid_single_word, &config, &budget)?.best;
+    let forms: Vec<String> = constituents.iter().map(|c| c.form.clone()).collect();
+
+    let tree = bracketer.bracket(stripped, constituents);
+
+    if budget.is_exhausted() {
+        // Bracketing was given up on, but the decomposition is complete, of all parts.
+        return Err(DecompositionError::BudgetExhausted(Some(forms)));
+    }
+
+    let tree = tree.expect("Decomposition must have constituents");
+    Ok(if strips_hints {
+        tree.map_spans(&|span| hinted.restore(span))
+    } else {
+        tree
+    })
+}
+
+/// Brackets decompositions, sharing one budget for all lookups.
+struct Bracketer<'s, 'c, F> {
+    is_valid_single_word: &'s F,
+    config: &'s DecompositionConfig<'c>,
+    budget: &'s Budget<'s, 'c>,
+}
+
+impl<F: Fn(&str) -> bool> Bracketer<'_, '_, F> {
+    /// Brackets `constituents` of `word`, which is free of boundary hints. Parts of
+    /// the word separated by hyphens are bracketed on their own first.
+    fn bracket(&self, word: &str, constituents: Vec<Constituent>) -> Option<CompoundTree> {
+        // Split on hyphens, constituents are separated by them.
+        let mut parts: Vec<Vec<Constituent>> = Vec::new();
+        for constituent in constituents {
+            match parts.last_mut() {
+                Some(part)
+                    if part
+                        .last()
+                        .map_or(false, |last| last.span.end == constituent.span.start) =>
+                {
+                    part.push(constituent);
+                }
+                _ => parts.push(vec![constituent]),
+            }
+        }
+
+        let trees: Vec<CompoundTree> = parts
+            .into_iter()
+            .filter_map(|part| {
+                let span = part.first()?.span.start..part.last()?.span.end;
+                let part = part
+                    .into_iter()
+                    .map(|mut c| {
+                        c.span = (c.span.start - span.start)..(c.span.end - span.start);
+                        c
+                    })
+                    .collect();
+
+                let tree = self.bracket_part(&word[span.clone()], part)?;
+                Some(tree.map_spans(&|s| (s.start + span.start)..(s.end + span.start)))
+            })
+            .collect();
+
+        match self.config.bracketing {
+            Bracketing::Search => CompoundTree::nested(trees),
+            Bracketing::LexiconEvidence => self.evidence(word).bracket(trees),
+        }
+    }
+
+    /// Brackets `constituents` of `word`, which is free of hyphens and boundary hints.
+    fn bracket_part(&self, word: &str, constituents: Vec<Constituent>) -> Option<CompoundTree> {
+        match self.config.bracketing {
+            Bracketing::Search => {
+                CompoundTree::nested(constituents.into_iter().map(CompoundTree::Leaf).collect())
+            }
+            Bracketing::LexiconEvidence => {
+                let leaves = constituents.into_iter().map(|c| self.expand(c)).collect();
+
+                self.evidence(word).bracket(leaves)
+            }
+        }
+    }
+
+    /// Decomposes `constituent` further if it is a compound itself, as a tree.
+    fn expand(&self, constituent: Constituent) -> CompoundTree {
+        let form = constituent.form();
+        let offset = constituent.span.start;
+
+        // Spans into the form only map onto the word if casing kept byte lengths.
+        if form.len() != constituent.span.len() {
+            return CompoundTree::Leaf(constituent);
+        }
+
+        let tree = decompose_word(
+            form,
+            &[],
+            self.is_valid_single_word,
+            self.config,
+            self.budget,
+        )
+        .ok()
+        .and_then(|ranked| self.bracket(form, ranked.best));
+
+        match tree {
+            Some(tree) => {
+                trace!("Constituent '{form}' is a compound itself: {tree}");
+                tree.map_spans(&|span| (span.start + offset)..(span.end + offset))
+            }
+            None => CompoundTree::Leaf(constituent),
+        }
+    }
+
+    /// Evidence on brackets in `word`, looked up like constituents during search.
+    fn evidence<'w>(&'w self, word: &'w str) -> Evidence<'w, 'w, F> {
+        Evidence {
+            search: Search {
+                word,
+                hints: &[],
+                split_points: None,
+                is_valid_single_word: self.is_valid_single_word,
+                config: self.config,
+                budget: self.budget,
+            },
+        }
+    }
+}
+
+/// Brackets subtrees by whether the compounds they form are valid words.
+struct Evidence<'s, 'c, F> {
+    /// Of the word bracketed, only to look up parts of it as constituents.
+    search: Search<'s, 'c, F>,
+}
+
+impl<F: Fn(&str) -> bool> Evidence<'_, '_, F> {
+    fn bracket(&self, leaves: Vec<CompoundTree>) -> Option<CompoundTree> {
+        let n = leaves.len();
+
+        // For all ranges of leaves `i..j`, the best tree and the number of its nodes
+        // which are valid words.
+        let mut best: Vec<Vec<Option<(usize, CompoundTree)>>> = vec![vec![None; n + 1]; n + 1];
+        for (i, leaf) in leaves.into_iter().enumerate() {
+            best[i][i + 1] = Some((0, leaf));
+        }
+
+        for len in 2..=n {
+            for i in 0..=(n - len) {
+                let j = i + len;
+
+                let mut splits: Vec<usize> = ((i + 1)..j).collect();
+                if self.search.config.headedness == Headedness::Right {
+                    // Split off the head on the right first.
+                    splits.reverse();
+                }
+
+                let mut found: Option<(usize, usize)> = None;
+                for k in splits {
+                    if let (Some((left, _)), Some((right, _))) = (&best[i][k], &best[k][j]) {
+                        let support = left + right;
+                        if found.map_or(true, |(_, best_support)| support > best_support) {
+                            found = Some((k, support));
+                        }
+                    }
+                }
+
+                if let Some((k, support)) = found {
+                    let left = best[i][k].clone().map(|(_, tree)| tree);
+                    let right = best[k][j].clone().map(|(_, tree)| tree);
+
+                    if let (Some(left), Some(right)) = (left, right) {
+                        let tree = CompoundTree::node(left, right);
+                        let is_word = len < n && self.is_valid_word(&tree);
+
+                        best[i][j] = Some((support + usize::from(is_word), tree));
+                    }
+                }
+            }
+        }
+
+        best[0][n].take().map(|(_, tree)| tree)
+    }
+
+    /// Whether the part of the word covered by `tree` is a valid word.
+    fn is_valid_word(&self, tree: &CompoundTree) -> bool {
+        let span = tree.span();
+        let is_valid = self.search.constituent(span.clone()).is_some();
+
+        if is_valid {
+            trace!(
+                "'{}' is a valid word, bracketing it",
+                &self.search.word[span]
+            );
+        }
+
+        is_valid
+    }
+}
@@ -0,0 +1,145 @@
+//! Marking constituent boundaries for typesetting.
+
+use std::{iter, ops::Range};
+
+use crate::{decompose, DecompositionConfig, DecompositionError};
+
+/// How [`mark_boundaries`] marks boundaries between constituents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoundaryMark {
+    /// Soft hyphen (`U+00AD`), an invisible line-break opportunity.
+    SoftHyphen,
+    /// Middle dot (`U+00B7`), a *visible* mark, as in `Donau·dampf·schiff`. Only
+    /// boundaries between constituents are marked, never those inside of them.
+    MiddleDot,
+}
+
+impl BoundaryMark {
+    /// The character inserted at boundaries.
+    #[must_use]
+    pub fn as_char(self) -> char {
+        match self {
+            BoundaryMark::SoftHyphen => '\u{00AD}',
+            BoundaryMark::MiddleDot => '\u{00B7}',
+        }
+    }
+}
+
+/// Returns `word` with `mark` inserted at every boundary between its constituents, as
+/// found by [`decompound`](crate::decompound). The original spelling of `word` is kept,
+/// even if constituents were found in another form (for example titlecased).
+///
+/// ```
+/// use decompound::{mark_boundaries, BoundaryMark, DecompositionOptions};
+///
+/// let is_valid_single_word = |w: &str| ["Donau", "Dampf", "Schiff"].contains(&w);
+///
+/// assert_eq!(
+///     mark_boundaries(
+///         "Donaudampfschiff",
+///         &is_valid_single_word,
+///         DecompositionOptions::TRY_TITLECASE_SUFFIX,
+///         BoundaryMark::MiddleDot,
+///     ).unwrap(),
+///     "Donau·dampf·schiff"
+/// );
+/// ```
+///
+/// A word which is valid but not a compound is returned unchanged.
+///
+/// ## Errors
+///
+/// [`DecompositionError::NothingValid`] if `word` is not valid at all.
+pub fn mark_boundaries<'a>(
+    word: impl AsRef<str>,
+    is_valid_single_word: &impl Fn(&str) -> bool,
+    config: impl Into<DecompositionConfig<'a>>,
+    mark: BoundaryMark,
+) -> Result<String, DecompositionError> {
+    mark_boundaries_with(word, is_valid_single_word, config, mark, |_| Vec::new())
+}
+
+/// Like [`mark_boundaries`], but additionally inserts soft hyphens *inside* of
+/// constituents, at the byte offsets `hyphenate` returns for each of them. This way, a
+/// regular hyphenator can supply break opportunities within constituents, while
+/// boundaries between them are always available.
+///
+/// `hyphenate` is handed each constituent as spelled in `word`. Offsets which are zero,
+/// out of bounds or not on a char boundary are ignored. For [`BoundaryMark::MiddleDot`],
+/// soft hyphens are still used inside of constituents, as the middle dot is only meant
+/// for compound boundaries.
+///
+/// ```
+/// use decompound::{mark_boundaries_with, BoundaryMark, DecompositionOptions};
+///
+/// let is_valid_single_word = |w: &str| ["Donau", "Dampf", "Schiff"].contains(&w);
+/// // A *very* naive hyphenator, for demonstration only
+/// let hyphenate = |constituent: &str| match constituent {
+///     "Donau" => vec![2],
+///     _ => vec![],
+/// };
+///
+/// assert_eq!(
+///     mark_boundaries_with(
+///         "Donaudampfschiff",
+///         &is_valid_single_word,
+///         DecompositionOptions::TRY_TITLECASE_SUFFIX,
+///         BoundaryMark::SoftHyphen,
+///         hyphenate,
+///     ).unwrap(),
+///     "Do\u{00AD}nau\u{00AD}dampf\u{00AD}schiff"
+/// );
+/// ```
+///
+/// ## Errors
+///
+/// Same as [`mark_boundaries`].
+pub fn mark_boundaries_with<'a>(
+    word: impl AsRef<str>,
+    is_valid_single_word: &impl Fn(&str) -> bool,
+    config: impl Into<DecompositionConfig<'a>>,
+    mark: BoundaryMark,
+    hyphenate: impl Fn(&str) -> Vec<usize>,
+) -> Result<String, DecompositionError> {
+    let word = word.as_ref();
+
+    let spans: Vec<Range<usize>> = match decompose(word, is_valid_single_word, &config.into()) {
+        Ok(constituents) => constituents.into_iter().map(|c| c.span).collect(),
+        Err(DecompositionError::SingleWord(_)) => iter::once(0..word.len()).collect(),
+        Err(e) => return Err(e),
+    };
+
+    let mut marked = String::with_capacity(word.len() + 2 * spans.len());
+    let mut previous_end = 0;
+
+    for (i, span) in spans.into_iter().enumerate() {
+        // Anything in between constituents, like hyphens or boundary hints, is kept. If
+        // that already is a break opportunity of the requested kind, don't add another.
+        let gap = &word[previous_end..span.start];
+        marked.push_str(gap);
+        if i > 0 && !gap.contains(['-', mark.as_char()]) {
+            marked.push(mark.as_char());
+        }
+
+        let constituent = &word[span.clone()];
+        let mut breaks = hyphenate(constituent);
+        breaks.sort_unstable();
+        breaks.dedup();
+
+        let mut last = 0;
+        for at in breaks
+            .into_iter()
+            .filter(|&at| at > 0 && at < constituent.len() && constituent.is_char_boundary(at))
+        {
+            marked.push_str(&constituent[last..at]);
+            marked.push(BoundaryMark::SoftHyphen.as_char());
+            last = at;
+        }
+        marked.push_str(&constituent[last..]);
+
+        previous_end = span.end;
+    }
+    marked.push_str(&word[previous_end..]);
+
+    Ok(marked)
+}
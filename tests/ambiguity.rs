@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests {
+    use decompound::{
+        decompound_with_ambiguity, Ambiguity, Ambiguity::*, DecompositionConfig as Config,
+        DecompositionError::*, DecompositionOptions as Opt, FrequencyLexicon,
+    };
+    use rstest::rstest;
+
+    const WORDS: &[&str] = &[
+        "Wach",
+        "Stube",
+        "Wachs",
+        "Tube",
+        "Stau",
+        "Becken",
+        "Staub",
+        "Ecken",
+        "Blumentopf",
+        "Erde",
+        "Blumento",
+        "Pferde",
+        "Haus",
+        "Boot",
+        "Entnahme",
+        "Stelle",
+        "St",
+        "Elle",
+    ];
+
+    #[rstest]
+    #[case("Wachstube", Config::new(Opt::TRY_TITLECASE_SUFFIX), vec!["Wach", "Stube"], Some(vec!["Wachs", "Tube"]), Tie, 0.0)]
+    #[case("Staubecken", Config::new(Opt::TRY_TITLECASE_SUFFIX), vec!["Stau", "Becken"], Some(vec!["Staub", "Ecken"]), Tie, 0.0)]
+    #[case("Blumentopferde", Config::new(Opt::TRY_TITLECASE_SUFFIX), vec!["Blumento", "Pferde"], Some(vec!["Blumentopf", "Erde"]), Tie, 0.0)]
+    #[case("Hausboot", Config::new(Opt::TRY_TITLECASE_SUFFIX), vec!["Haus", "Boot"], None, Unique, f64::INFINITY)]
+    #[case("Entnahmestelle", Config::new(Opt::TRY_TITLECASE_SUFFIX), vec!["Entnahme", "Stelle"], Some(vec!["Entnahme", "St", "Elle"]), Unique, 1.0)]
+    #[case("Entnahmestelle", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_near_tie_margin(0.5), vec!["Entnahme", "Stelle"], Some(vec!["Entnahme", "St", "Elle"]), NearTie, 1.0)]
+    #[case("Entnahmestelle", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER), vec!["Entnahme", "St", "Elle"], Some(vec!["Entnahme", "Stelle"]), Unique, 1.0)]
+    // Hints take precedence
+    #[case("Wachs\u{00AD}tube", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS), vec!["Wachs", "Tube"], Some(vec!["Wach", "Stube"]), Unique, f64::INFINITY)]
+    // Most ambiguous part decides
+    #[case("Hausboot-Wachstube-Entnahmestelle", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED), vec!["Haus", "Boot", "Wach", "Stube", "Entnahme", "Stelle"], Some(vec!["Haus", "Boot", "Wachs", "Tube", "Entnahme", "Stelle"]), Tie, 0.0)]
+    #[case("Haus-Entnahmestelle", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED), vec!["Haus", "Entnahme", "Stelle"], Some(vec!["Haus", "Entnahme", "St", "Elle"]), Unique, 1.0)]
+    #[case("Haus-Boot", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED), vec!["Haus", "Boot"], None, Unique, f64::INFINITY)]
+    fn test_ambiguity(
+        #[case] word: &str,
+        #[case] config: Config,
+        #[case] constituents: Vec<&str>,
+        #[case] runner_up: Option<Vec<&str>>,
+        #[case] ambiguity: Ambiguity,
+        #[case] margin: f64,
+    ) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        let report = decompound_with_ambiguity(word, &is_valid_single_word, config).unwrap();
+
+        assert_eq!(report.constituents(), constituents);
+        assert_eq!(
+            report.runner_up().map(<[String]>::to_vec),
+            runner_up.map(|v| v.into_iter().map(ToOwned::to_owned).collect())
+        );
+        assert_eq!(report.ambiguity(), ambiguity);
+        assert_eq!(report.margin(), margin);
+    }
+
+    #[rstest]
+    #[case("Baum\t1000\nAst\t1000\nBau\t1000\nMast\t900", vec!["Baum", "Ast"], NearTie)]
+    #[case("Baum\t1000\nAst\t1000\nBau\t1000\nMast\t100", vec!["Baum", "Ast"], Unique)]
+    #[case("Baum\t1000\nAst\t1000\nBau\t1000\nMast\t1000", vec!["Bau", "Mast"], Tie)]
+    fn test_ambiguity_with_frequencies(
+        #[case] frequencies: &str,
+        #[case] constituents: Vec<&str>,
+        #[case] ambiguity: Ambiguity,
+    ) {
+        let frequencies = FrequencyLexicon::parse(frequencies);
+        let is_valid_single_word = |w: &str| frequencies.contains(w);
+
+        let report = decompound_with_ambiguity(
+            "Baumast",
+            &is_valid_single_word,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_frequencies(&frequencies),
+        )
+        .unwrap();
+
+        assert_eq!(report.constituents(), constituents);
+        assert_eq!(report.ambiguity(), ambiguity);
+    }
+
+    #[rstest]
+    #[case("Haus", SingleWord("Haus".into()))]
+    #[case("Hausxboot", NothingValid)]
+    fn test_ambiguity_errors(#[case] word: &str, #[case] expected: decompound::DecompositionError) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        assert_eq!(
+            decompound_with_ambiguity(word, &is_valid_single_word, Opt::TRY_TITLECASE_SUFFIX),
+            Err(expected)
+        );
+    }
+}
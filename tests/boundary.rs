@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use decompound::{
+        decompound, german_boundary, DecompositionConfig as Config, DecompositionError,
+        DecompositionError::*, DecompositionOptions as Opt,
+    };
+    use rstest::rstest;
+
+    type DecompositionTestResult<'a> = Result<Vec<&'a str>, DecompositionError>;
+
+    const WORDS: &[&str] = &[
+        "Tisc", "Hecke", "Tisch", "Ecke", "Bac", "Kofen", "Back", "Ofen", "B", "Ärchen", "Bär",
+        "Chen", "Aq", "Uarium", "Aquarium", "S", "Tau", "Stau",
+    ];
+
+    #[rstest]
+    #[case("Tisch", "Ecke", true)]
+    #[case("Tisc", "Hecke", false)]
+    #[case("Tis", "Checke", false)]
+    #[case("Bac", "Kofen", false)]
+    #[case("Aq", "Uarium", false)]
+    #[case("Bär", "Chen", true)]
+    #[case("S", "Tau", false)]
+    #[case("Stau", "S", false)]
+    #[case("Über", "Ich", true)]
+    fn test_german_boundary(#[case] left: &str, #[case] right: &str, #[case] expected: bool) {
+        assert_eq!(german_boundary(left, right), expected);
+    }
+
+    #[rstest]
+    #[case("Tischecke", Ok(vec!["Tisch", "Ecke"]))]
+    #[case("Backofen", Ok(vec!["Back", "Ofen"]))]
+    #[case("Bärchen", Ok(vec!["Bär", "Chen"]))]
+    #[case("Stau", Err(SingleWord("Stau".into())))]
+    #[case("Aquarium", Err(SingleWord("Aquarium".into())))]
+    // Explicit hyphens are not up for debate
+    #[case("Tisc-Hecke", Ok(vec!["Tisc", "Hecke"]))]
+    #[case("Tisch-Ecke", Ok(vec!["Tisch", "Ecke"]))]
+    fn test_decompound_with_german_boundary(
+        #[case] word: &str,
+        #[case] expected: DecompositionTestResult,
+    ) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+        let config = Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED)
+            .with_boundary_predicate(&german_boundary);
+
+        assert_eq!(
+            decompound(word, &is_valid_single_word, config),
+            expected.map(|v| v.into_iter().map(ToOwned::to_owned).collect())
+        );
+    }
+}
@@ -0,0 +1,190 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::Cell,
+        sync::atomic::AtomicBool,
+        time::{Duration, Instant},
+    };
+
+    use decompound::{
+        decompound, DecompositionConfig as Config, DecompositionError::*,
+        DecompositionOptions as Opt, SearchStrategy,
+    };
+    use rstest::rstest;
+
+    const WORDS: &[&str] = &["Donau", "Dampf", "Schiff", "Fahrt", "Haus", "Boot"];
+
+    #[rstest]
+    fn test_max_validator_calls(
+        #[values(
+            SearchStrategy::Exhaustive,
+            SearchStrategy::GreedyLongestPrefix,
+            SearchStrategy::GreedyLongestSuffix,
+            SearchStrategy::Beam { width: 2 }
+        )]
+        strategy: SearchStrategy,
+    ) {
+        let calls = Cell::new(0);
+        let is_valid_single_word = |w: &str| {
+            calls.set(calls.get() + 1);
+            WORDS.contains(&w)
+        };
+        let config = Config::new(Opt::TRY_TITLECASE_SUFFIX).with_strategy(strategy);
+        let expected = vec!["Donau", "Dampf", "Schiff"];
+
+        assert_eq!(
+            decompound("Donaudampfschiff", &is_valid_single_word, &config).unwrap(),
+            expected
+        );
+        let needed = calls.replace(0);
+
+        assert_eq!(
+            decompound(
+                "Donaudampfschiff",
+                &is_valid_single_word,
+                config.clone().with_max_validator_calls(needed)
+            )
+            .unwrap(),
+            expected
+        );
+
+        for max in 0..needed {
+            calls.set(0);
+            let result = decompound(
+                "Donaudampfschiff",
+                &is_valid_single_word,
+                config.clone().with_max_validator_calls(max),
+            );
+
+            assert!(calls.get() <= max);
+            assert!(
+                matches!(&result, Err(BudgetExhausted(best)) if best.iter().all(|b| b == &expected)),
+                "{result:?}"
+            );
+        }
+    }
+
+    #[rstest]
+    #[case("Haus", Config::new(Opt::TRY_TITLECASE_SUFFIX), Err(SingleWord("Haus".into())))]
+    #[case("Hausxboot", Config::new(Opt::TRY_TITLECASE_SUFFIX), Err(NothingValid))]
+    #[case("Hausboot", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_min_word_len(20), Err(NothingValid))]
+    #[case("Donau", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_min_word_len(20), Err(SingleWord("Donau".into())))]
+    #[case("Donaudampfschiff", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_max_constituents(2), Err(NothingValid))]
+    #[case("Haus-Boot", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED).with_max_constituents(1), Err(NothingValid))]
+    fn test_max_validator_calls_for_whole_word(
+        #[case] word: &str,
+        #[case] config: Config,
+        #[case] expected: Result<Vec<String>, decompound::DecompositionError>,
+    ) {
+        let calls = Cell::new(0);
+        let is_valid_single_word = |w: &str| {
+            calls.set(calls.get() + 1);
+            WORDS.contains(&w)
+        };
+
+        assert_eq!(decompound(word, &is_valid_single_word, &config), expected);
+        let needed = calls.replace(0);
+
+        // Running out while looking up the word as a whole is running out all the same.
+        for max in 0..needed {
+            calls.set(0);
+            let result = decompound(
+                word,
+                &is_valid_single_word,
+                config.clone().with_max_validator_calls(max),
+            );
+
+            assert!(
+                calls.get() <= max,
+                "{} calls for at most {max}",
+                calls.get()
+            );
+            assert_eq!(result, Err(BudgetExhausted(None)), "at most {max} calls");
+        }
+    }
+
+    #[test]
+    fn test_best_so_far() {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+        let config = Config::new(Opt::TRY_TITLECASE_SUFFIX);
+
+        // Enough to find a decomposition, but not to finish looking for others
+        assert_eq!(
+            decompound(
+                "Hausboot",
+                &is_valid_single_word,
+                config.clone().with_max_explored_splits(4)
+            ),
+            Err(BudgetExhausted(Some(vec!["Haus".into(), "Boot".into()])))
+        );
+        assert_eq!(
+            decompound(
+                "Hausboot",
+                &is_valid_single_word,
+                config.with_max_explored_splits(13)
+            ),
+            Ok(vec!["Haus".into(), "Boot".into()])
+        );
+    }
+
+    #[rstest]
+    // Running out in the last part, all parts before it decomposed
+    #[case("Donau-Hausboot", Err(BudgetExhausted(Some(vec!["Donau".into(), "Haus".into(), "Boot".into()]))))]
+    // Running out before the last part, which is not searched at all
+    #[case("Hausboot-Donau", Err(BudgetExhausted(None)))]
+    fn test_best_so_far_hyphenated(
+        #[case] word: &str,
+        #[case] expected: Result<Vec<String>, decompound::DecompositionError>,
+    ) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        assert_eq!(
+            decompound(
+                word,
+                &is_valid_single_word,
+                Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED)
+                    .with_max_explored_splits(8)
+            ),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(Config::new(Opt::TRY_TITLECASE_SUFFIX).with_max_explored_splits(0), Err(BudgetExhausted(None)))]
+    #[case(Config::new(Opt::TRY_TITLECASE_SUFFIX).with_max_explored_splits(2), Err(BudgetExhausted(None)))]
+    #[case(Config::new(Opt::TRY_TITLECASE_SUFFIX).with_max_explored_splits(1000), Ok(vec!["Donau".into(), "Dampf".into(), "Schiff".into(), "Fahrt".into()]))]
+    #[case(Config::new(Opt::TRY_TITLECASE_SUFFIX).with_deadline(Instant::now()), Err(BudgetExhausted(None)))]
+    #[case(Config::new(Opt::TRY_TITLECASE_SUFFIX).with_deadline(Instant::now() + Duration::from_secs(3600)), Ok(vec!["Donau".into(), "Dampf".into(), "Schiff".into(), "Fahrt".into()]))]
+    #[case(Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED).with_max_validator_calls(20), Err(BudgetExhausted(None)))]
+    fn test_limits(
+        #[case] config: Config,
+        #[case] expected: Result<Vec<String>, decompound::DecompositionError>,
+    ) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        assert_eq!(
+            decompound("Donaudampfschifffahrt", &is_valid_single_word, config),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(false, Ok(vec!["Haus".into(), "Boot".into()]))]
+    #[case(true, Err(BudgetExhausted(None)))]
+    fn test_cancellation(
+        #[case] cancelled: bool,
+        #[case] expected: Result<Vec<String>, decompound::DecompositionError>,
+    ) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+        let flag = AtomicBool::new(cancelled);
+
+        assert_eq!(
+            decompound(
+                "Hausboot",
+                &is_valid_single_word,
+                Config::new(Opt::TRY_TITLECASE_SUFFIX).with_cancellation_flag(&flag)
+            ),
+            expected
+        );
+    }
+}
@@ -1,8 +1,38 @@
 #[cfg(test)]
 mod tests {
+    use std::{
+        path::PathBuf,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
     use assert_cmd::Command;
     use rstest::rstest;
 
+    /// A file in the temporary directory, unique to this test run, removed on drop.
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(contents: &str) -> Self {
+            static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+            let path = std::env::temp_dir().join(format!(
+                "{}-{}-{}.tsv",
+                env!("CARGO_PKG_NAME"),
+                std::process::id(),
+                COUNT.fetch_add(1, Ordering::Relaxed)
+            ));
+            std::fs::write(&path, contents).unwrap();
+
+            Self(path)
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
     #[rstest]
     fn test_cli() {
         // Should rebuild the binary to `target/debug/<name>`. This works if running as an
@@ -24,4 +54,86 @@ mod tests {
 
         assert_eq!(output.trim(), "Affen\nGruppen\nÜberfall");
     }
+
+    #[rstest]
+    #[case(&[], "")]
+    #[case(&["--gender-inclusive"], "LehrerInnen\nZimmer")]
+    #[case(&["-g"], "LehrerInnen\nZimmer")]
+    fn test_cli_gender_inclusive(#[case] flags: &[&str], #[case] expected: &str) {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+
+        const WORDS: &[&str] = &["Lehrer", "Lehrerinnen", "Zimmer"];
+
+        cmd.arg("--try-titlecase-suffix")
+            .args(flags)
+            .arg("LehrerInnenzimmer")
+            .write_stdin(WORDS.join("\n"));
+
+        let raw_output = cmd.output().unwrap().stdout;
+        let output = String::from_utf8(raw_output).unwrap();
+
+        assert_eq!(output.trim(), expected);
+    }
+
+    #[rstest]
+    #[case(&[], "")]
+    #[case(&["--boundary-hints"], "Wach\nStube")]
+    #[case(&["-b"], "Wach\nStube")]
+    fn test_cli_boundary_hints(#[case] flags: &[&str], #[case] expected: &str) {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+
+        const WORDS: &[&str] = &["Wach", "Stube", "Wachs", "Tube"];
+
+        // Soft hyphen
+        cmd.arg("--try-titlecase-suffix")
+            .args(flags)
+            .arg("Wach\u{00AD}stube")
+            .write_stdin(WORDS.join("\n"));
+
+        let raw_output = cmd.output().unwrap().stdout;
+        let output = String::from_utf8(raw_output).unwrap();
+
+        assert_eq!(output.trim(), expected);
+    }
+
+    #[rstest]
+    #[case(None, "Bau\nMast")]
+    #[case(Some("Baum\t5000\nAst\t1200\nBau\t3000\nMast\t200\n"), "Baum\nAst")]
+    fn test_cli_frequencies(#[case] frequencies: Option<&str>, #[case] expected: &str) {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+
+        const WORDS: &[&str] = &["Baum", "Ast", "Bau", "Mast"];
+
+        let _ = cmd.arg("--try-titlecase-suffix");
+        // Kept alive until the command has run.
+        let file = frequencies.map(TempFile::new);
+        if let Some(file) = &file {
+            let _ = cmd.arg("--frequencies").arg(&file.0);
+        }
+        cmd.arg("Baumast").write_stdin(WORDS.join("\n"));
+
+        let raw_output = cmd.output().unwrap().stdout;
+        let output = String::from_utf8(raw_output).unwrap();
+
+        assert_eq!(output.trim(), expected);
+    }
+
+    #[rstest]
+    fn test_cli_dot() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+
+        const WORDS: &[&str] = &["Haus", "Boot"];
+
+        cmd.args(["--try-titlecase-suffix", "--dot", "Hausboot"])
+            .write_stdin(WORDS.join("\n"));
+
+        let raw_output = cmd.output().unwrap().stdout;
+        let output = String::from_utf8(raw_output).unwrap();
+
+        assert!(output.starts_with("digraph decomposition {"));
+        assert!(
+            output.contains(r#"4 -> 8 [label="Boot", color=blue, fontcolor=blue, penwidth=2];"#)
+        );
+        assert!(output.trim_end().ends_with('}'));
+    }
 }
@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tests {
+    use decompound::{
+        decompound, DecompositionConfig as Config, DecompositionError, DecompositionError::*,
+        DecompositionOptions as Opt,
+    };
+    use rstest::rstest;
+
+    type DecompositionTestResult<'a> = Result<Vec<&'a str>, DecompositionError>;
+
+    const WORDS: &[&str] = &[
+        "A",
+        "Bahn",
+        "Autobahn",
+        "Auto",
+        "Raststätte",
+        "Rast",
+        "Stätte",
+        "St",
+        "Ätte",
+        "Entnahme",
+        "Stelle",
+        "Elle",
+        "Donau",
+        "Dampf",
+        "Schiff",
+        "Fahrt",
+        "Ei",
+        "Eis",
+        "Sport",
+    ];
+
+    #[rstest]
+    #[case("Autobahnraststätte", Config::new(Opt::TRY_TITLECASE_SUFFIX), Ok(vec!["Autobahn", "Raststätte"]))]
+    #[case("Autobahnraststätte", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER), Ok(vec!["Auto", "Bahn", "Rast", "St", "Ätte"]))]
+    #[case("Autobahnraststätte", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER).with_min_constituent_len(3), Ok(vec!["Auto", "Bahn", "Rast", "Stätte"]))]
+    #[case("Autobahnraststätte", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER).with_min_constituent_len(5), Ok(vec!["Autobahn", "Raststätte"]))]
+    #[case("Autobahnraststätte", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_min_constituent_len(11), Err(NothingValid))]
+    #[case("Abahn", Config::new(Opt::TRY_TITLECASE_SUFFIX), Ok(vec!["A", "Bahn"]))]
+    #[case("Abahn", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_min_constituent_len(2), Err(NothingValid))]
+    // Single words are not subject to it
+    #[case("A", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_min_constituent_len(2), Err(SingleWord("A".into())))]
+    // Counted in chars, not bytes
+    #[case("Eisätte", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_min_constituent_len(4), Err(NothingValid))]
+    #[case("Eisätte", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_min_constituent_len(3), Ok(vec!["Eis", "Ätte"]))]
+    fn test_min_constituent_len(
+        #[case] word: &str,
+        #[case] config: Config,
+        #[case] expected: DecompositionTestResult,
+    ) {
+        assert_decompound(word, &config, expected);
+    }
+
+    #[rstest]
+    #[case("Autobahnraststätte", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER).with_max_constituents(5), Ok(vec!["Auto", "Bahn", "Rast", "St", "Ätte"]))]
+    #[case("Autobahnraststätte", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER).with_max_constituents(4), Ok(vec!["Autobahn", "Rast", "St", "Ätte"]))]
+    #[case("Autobahnraststätte", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER).with_max_constituents(2), Ok(vec!["Autobahn", "Raststätte"]))]
+    #[case("Donaudampfschifffahrt", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_max_constituents(3), Err(NothingValid))]
+    #[case("Donaudampfschifffahrt", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_max_constituents(4), Ok(vec!["Donau", "Dampf", "Schiff", "Fahrt"]))]
+    #[case("Autobahn", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER).with_max_constituents(1), Err(SingleWord("Autobahn".into())))]
+    // Hyphenated parts count together
+    #[case("Eissport-Donau", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED).with_max_constituents(3), Ok(vec!["Eis", "Sport", "Donau"]))]
+    #[case("Eissport-Donaudampf", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED).with_max_constituents(3), Err(NothingValid))]
+    #[case("Eissport-Donaudampf", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED).with_max_constituents(4), Ok(vec!["Eis", "Sport", "Donau", "Dampf"]))]
+    // Each part is searched within what the others leave, not shattered beyond it.
+    #[case("Autobahnraststätte-Donau", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED | Opt::SHATTER).with_max_constituents(6), Ok(vec!["Auto", "Bahn", "Rast", "St", "Ätte", "Donau"]))]
+    #[case("Autobahnraststätte-Donau", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED | Opt::SHATTER).with_max_constituents(5), Ok(vec!["Autobahn", "Rast", "St", "Ätte", "Donau"]))]
+    #[case("Autobahnraststätte-Donau", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED | Opt::SHATTER).with_max_constituents(3), Ok(vec!["Autobahn", "Raststätte", "Donau"]))]
+    #[case("Donau-Autobahnraststätte", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED | Opt::SHATTER).with_max_constituents(3), Ok(vec!["Donau", "Autobahn", "Raststätte"]))]
+    #[case("Autobahnraststätte-Donau", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED | Opt::SHATTER).with_max_constituents(2), Err(NothingValid))]
+    fn test_max_constituents(
+        #[case] word: &str,
+        #[case] config: Config,
+        #[case] expected: DecompositionTestResult,
+    ) {
+        assert_decompound(word, &config, expected);
+    }
+
+    #[rstest]
+    #[case("Donaudampfschifffahrt", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_max_depth(0), Err(NothingValid))]
+    #[case("Donaudampfschifffahrt", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_max_depth(1), Err(NothingValid))]
+    #[case("Donaudampfschifffahrt", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_max_depth(2), Ok(vec!["Donau", "Dampf", "Schiff", "Fahrt"]))]
+    #[case("Donaudampf", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_max_depth(0), Ok(vec!["Donau", "Dampf"]))]
+    #[case("Autobahnraststätte", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER).with_max_depth(1), Ok(vec!["Autobahn", "Rast", "Stätte"]))]
+    // Per hyphen-separated part
+    #[case("Donaudampf-Eissport", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED).with_max_depth(0), Ok(vec!["Donau", "Dampf", "Eis", "Sport"]))]
+    fn test_max_depth(
+        #[case] word: &str,
+        #[case] config: Config,
+        #[case] expected: DecompositionTestResult,
+    ) {
+        assert_decompound(word, &config, expected);
+    }
+
+    #[rstest]
+    #[case("Eissport", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_min_word_len(8), Ok(vec!["Eis", "Sport"]))]
+    #[case("Eissport", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_min_word_len(9), Err(NothingValid))]
+    #[case("Autobahn", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_min_word_len(9), Err(SingleWord("Autobahn".into())))]
+    #[case("Eisätte", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_min_word_len(7), Ok(vec!["Eis", "Ätte"]))]
+    #[case("Ei-Sport", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED).with_min_word_len(8), Ok(vec!["Ei", "Sport"]))]
+    #[case("Ei-Sport", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED).with_min_word_len(9), Err(NothingValid))]
+    fn test_min_word_len(
+        #[case] word: &str,
+        #[case] config: Config,
+        #[case] expected: DecompositionTestResult,
+    ) {
+        assert_decompound(word, &config, expected);
+    }
+
+    #[test]
+    fn test_options_convert_into_config() {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        assert_eq!(
+            decompound("Eissport", &is_valid_single_word, Opt::TRY_TITLECASE_SUFFIX),
+            decompound(
+                "Eissport",
+                &is_valid_single_word,
+                Config::new(Opt::TRY_TITLECASE_SUFFIX)
+            )
+        );
+    }
+
+    fn assert_decompound(word: &str, config: &Config, expected: DecompositionTestResult) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        assert_eq!(
+            decompound(word, &is_valid_single_word, config),
+            expected.map(|v| v.into_iter().map(ToOwned::to_owned).collect())
+        );
+    }
+}
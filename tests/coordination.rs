@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+    use decompound::{
+        expand_coordination, DecompositionError, DecompositionError::*, DecompositionOptions as Opt,
+    };
+    use rstest::rstest;
+
+    const WORDS: &[&str] = &[
+        "Ein",
+        "Aus",
+        "Gang",
+        "Haupt",
+        "Neben",
+        "Satz",
+        "Kranken",
+        "Pflege",
+        "Versicherung",
+        "Vor",
+        "Nach",
+        "Teile",
+        "Schul",
+        "Bücher",
+        "Durch",
+        "Haus",
+        "Hof",
+        "Hund",
+    ];
+
+    #[rstest]
+    #[case("Ein- und Ausgang", Ok(vec!["Eingang", "Ausgang"]))]
+    #[case("Haupt- und Nebensatz", Ok(vec!["Hauptsatz", "Nebensatz"]))]
+    #[case("Kranken- und Pflegeversicherung", Ok(vec!["Krankenversicherung", "Pflegeversicherung"]))]
+    #[case("Vor-/Nachteile", Ok(vec!["Vorteile", "Nachteile"]))]
+    #[case("Vor- bzw. Nachteile", Ok(vec!["Vorteile", "Nachteile"]))]
+    #[case("Ein-, Durch- oder Ausgang", Ok(vec!["Eingang", "Durchgang", "Ausgang"]))]
+    #[case("Schulbücher und -hefte", Ok(vec!["Schulbücher", "Schulhefte"]))]
+    //
+    // Surrounding punctuation
+    #[case("Haus- und Hofhund,", Ok(vec!["Haushund", "Hofhund"]))]
+    #[case("Haus- und Hofhund.", Ok(vec!["Haushund", "Hofhund"]))]
+    #[case("(Ein- und Ausgang)", Ok(vec!["Eingang", "Ausgang"]))]
+    #[case("„Vor- bzw. Nachteile!“", Ok(vec!["Vorteile", "Nachteile"]))]
+    #[case("Schulbücher und -hefte;", Ok(vec!["Schulbücher", "Schulhefte"]))]
+    #[case("Ein- und. Ausgang", Ok(vec!["Eingang", "Ausgang"]))]
+    //
+    // Nothing elided, nothing to do
+    #[case("Ausgang", Ok(vec!["Ausgang"]))]
+    #[case("Eingang und Ausgang", Ok(vec!["Eingang", "Ausgang"]))]
+    #[case("", Ok(vec![]))]
+    //
+    // No complete member to borrow from, in the respective direction
+    #[case("Ein- und Aus-", Err(NothingValid))]
+    #[case("Ausgang und Ein-", Err(NothingValid))]
+    #[case("-hefte und Schulbücher", Err(NothingValid))]
+    //
+    // Bare hyphens are no members
+    #[case("Haus - und Hoftür", Err(NothingValid))]
+    #[case("Haus -- Hoftür", Err(NothingValid))]
+    #[case("-", Err(NothingValid))]
+    //
+    // Donor is not a compound word
+    #[case("Ein- und Tür", Err(NothingValid))]
+    #[case("Ein- und Gang", Err(NothingValid))]
+    fn test_expand_coordination(
+        #[case] phrase: &str,
+        #[case] expected: Result<Vec<&str>, DecompositionError>,
+    ) {
+        assert_eq!(
+            expand_coordination(phrase, &|w| WORDS.contains(&w), Opt::TRY_TITLECASE_SUFFIX),
+            expected.map(|v| v.into_iter().map(String::from).collect())
+        );
+    }
+
+    #[rstest]
+    #[case(Opt::empty(), Err(NothingValid))]
+    #[case(Opt::TRY_TITLECASE_SUFFIX, Ok(vec!["Eingang", "Ausgang"]))]
+    fn test_expand_coordination_respects_options(
+        #[case] options: Opt,
+        #[case] expected: Result<Vec<&str>, DecompositionError>,
+    ) {
+        assert_eq!(
+            expand_coordination("Ein- und Ausgang", &|w| WORDS.contains(&w), options),
+            expected.map(|v| v.into_iter().map(String::from).collect())
+        );
+    }
+}
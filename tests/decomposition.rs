@@ -0,0 +1,275 @@
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, collections::HashMap};
+
+    use decompound::{
+        decompound_headed, decompound_with_metadata, DecompositionConfig as Config,
+        DecompositionError::*, DecompositionOptions as Opt, Headedness, Role, SearchStrategy,
+    };
+    use rstest::rstest;
+
+    const WORDS: &[&str] = &[
+        "Donau", "Dampf", "Schiff", "Haus", "Boot", "bed", "room", "super", "hero",
+    ];
+
+    #[rstest]
+    #[case("Hausboot", Config::new(Opt::TRY_TITLECASE_SUFFIX), "Boot", vec!["Haus"])]
+    #[case("Donaudampfschiff", Config::new(Opt::TRY_TITLECASE_SUFFIX), "Schiff", vec!["Donau", "Dampf"])]
+    #[case("Donaudampfschiff", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_headedness(Headedness::Right), "Schiff", vec!["Donau", "Dampf"])]
+    #[case("Donaudampfschiff", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_headedness(Headedness::Left), "Donau", vec!["Dampf", "Schiff"])]
+    #[case("bedroom-superhero", Config::new(Opt::SPLIT_HYPHENATED), "hero", vec!["bed", "room", "super"])]
+    #[case("bedroom-superhero", Config::new(Opt::SPLIT_HYPHENATED).with_headedness(Headedness::Left), "bed", vec!["room", "super", "hero"])]
+    fn test_head_and_modifiers(
+        #[case] word: &str,
+        #[case] config: Config,
+        #[case] head: &str,
+        #[case] modifiers: Vec<&str>,
+    ) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        let decomposition = decompound_headed(word, &is_valid_single_word, config).unwrap();
+
+        assert_eq!(decomposition.head().form(), head);
+        assert_eq!(
+            decomposition
+                .modifiers()
+                .iter()
+                .map(|c| c.form())
+                .collect::<Vec<_>>(),
+            modifiers
+        );
+        assert_eq!(
+            decomposition.constituents().len(),
+            decomposition.modifiers().len() + 1
+        );
+    }
+
+    #[rstest]
+    #[case(Headedness::Right, Some("neuter"))]
+    #[case(Headedness::Left, None)]
+    fn test_head_metadata(#[case] headedness: Headedness, #[case] expected: Option<&str>) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+        let genders = HashMap::from([("Boot", "neuter")]);
+
+        let decomposition = decompound_headed(
+            "Hausboot",
+            &is_valid_single_word,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_headedness(headedness),
+        )
+        .unwrap();
+
+        assert_eq!(decomposition.headedness(), headedness);
+        assert_eq!(
+            decomposition.head_metadata(|w| genders.get(w).copied()),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_head_metadata_missing() {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        let decomposition =
+            decompound_headed("Hausboot", &is_valid_single_word, Opt::TRY_TITLECASE_SUFFIX)
+                .unwrap();
+
+        assert_eq!(decomposition.head_metadata(|_| None::<()>), None);
+    }
+
+    #[rstest]
+    #[case("Haus", SingleWord("Haus".into()))]
+    #[case("Hausxboot", NothingValid)]
+    fn test_decompound_headed_errors(
+        #[case] word: &str,
+        #[case] expected: decompound::DecompositionError,
+    ) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        assert_eq!(
+            decompound_headed(word, &is_valid_single_word, Opt::TRY_TITLECASE_SUFFIX),
+            Err(expected)
+        );
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Pos {
+        Noun(&'static str),
+        Verb,
+    }
+
+    fn lexicon(w: &str) -> Option<Pos> {
+        match w {
+            "Rad" | "Schloss" | "Garten" | "Kinder" => Some(Pos::Noun("neuter")),
+            "Bahn" => Some(Pos::Noun("feminine")),
+            "Fahr" => Some(Pos::Verb),
+            _ => None,
+        }
+    }
+
+    fn noun_head(pos: &Pos, role: Role) -> bool {
+        role == Role::Modifier || matches!(pos, Pos::Noun(_))
+    }
+
+    fn anything(_: &Pos, _: Role) -> bool {
+        true
+    }
+
+    #[rstest]
+    #[case("Fahrradschloss", Config::new(Opt::TRY_TITLECASE_SUFFIX), &noun_head, Ok(vec!["Fahr", "Rad", "Schloss"]))]
+    #[case("Fahrradschloss", Config::new(Opt::TRY_TITLECASE_SUFFIX), &anything, Ok(vec!["Fahr", "Rad", "Schloss"]))]
+    #[case("Fahrradbahn", Config::new(Opt::TRY_TITLECASE_SUFFIX), &noun_head, Ok(vec!["Fahr", "Rad", "Bahn"]))]
+    #[case("Radfahr", Config::new(Opt::TRY_TITLECASE_SUFFIX), &noun_head, Err(NothingValid))]
+    #[case("Radfahr", Config::new(Opt::TRY_TITLECASE_SUFFIX), &anything, Ok(vec!["Rad", "Fahr"]))]
+    #[case("Fahrrad", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_headedness(Headedness::Left), &noun_head, Err(NothingValid))]
+    #[case("Radfahr", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_headedness(Headedness::Left), &noun_head, Ok(vec!["Rad", "Fahr"]))]
+    // Only the outermost hyphen-separated part has the head, the others' are modifiers.
+    #[case("Fahrrad-Bahn", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED), &noun_head, Ok(vec!["Fahr", "Rad", "Bahn"]))]
+    #[case("Radfahr-Bahn", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED), &noun_head, Ok(vec!["Rad", "Fahr", "Bahn"]))]
+    #[case("Fahr-Radschloss", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED), &noun_head, Ok(vec!["Fahr", "Rad", "Schloss"]))]
+    #[case("Bahn-Radfahr", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED), &noun_head, Err(NothingValid))]
+    #[case("Bahn-Fahr", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED), &noun_head, Err(NothingValid))]
+    #[case("Fahr-Bahn", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED).with_headedness(Headedness::Left), &noun_head, Err(NothingValid))]
+    #[case("Bahn-Fahr", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED).with_headedness(Headedness::Left), &noun_head, Ok(vec!["Bahn", "Fahr"]))]
+    // Single words need to be valid heads, too.
+    #[case("Rad", Config::new(Opt::TRY_TITLECASE_SUFFIX), &noun_head, Err(SingleWord("Rad".into())))]
+    #[case("Fahr", Config::new(Opt::TRY_TITLECASE_SUFFIX), &noun_head, Err(NothingValid))]
+    fn test_metadata_rules(
+        #[case] word: &str,
+        #[case] config: Config,
+        #[case] rule: &dyn Fn(&Pos, Role) -> bool,
+        #[case] expected: Result<Vec<&str>, decompound::DecompositionError>,
+    ) {
+        let result = decompound_with_metadata(word, &lexicon, &rule, config);
+
+        assert_eq!(
+            result.map(|d| d
+                .constituents()
+                .iter()
+                .map(|c| c.form().to_owned())
+                .collect::<Vec<_>>()),
+            expected.map(|v| v.into_iter().map(ToOwned::to_owned).collect())
+        );
+    }
+
+    #[rstest]
+    #[case("Fahrradschloss", Headedness::Right, Some(Pos::Noun("neuter")))]
+    #[case("Fahrradbahn", Headedness::Right, Some(Pos::Noun("feminine")))]
+    #[case("Bahnrad", Headedness::Left, Some(Pos::Noun("feminine")))]
+    #[case("Radschloss-Bahn", Headedness::Right, Some(Pos::Noun("feminine")))]
+    #[case("Bahn-Radschloss", Headedness::Right, Some(Pos::Noun("neuter")))]
+    #[case("Bahn-Radschloss", Headedness::Left, Some(Pos::Noun("feminine")))]
+    fn test_compound_metadata(
+        #[case] word: &str,
+        #[case] headedness: Headedness,
+        #[case] expected: Option<Pos>,
+    ) {
+        let decomposition = decompound_with_metadata(
+            word,
+            &lexicon,
+            &noun_head,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED)
+                .with_headedness(headedness),
+        )
+        .unwrap();
+
+        assert_eq!(decomposition.compound_metadata(), expected.as_ref());
+        assert_eq!(
+            decomposition.metadata()[0],
+            lexicon(decomposition.constituents()[0].form())
+        );
+    }
+
+    #[test]
+    fn test_metadata_per_constituent() {
+        let decomposition = decompound_with_metadata(
+            "Fahrradschloss",
+            &lexicon,
+            &noun_head,
+            Opt::TRY_TITLECASE_SUFFIX,
+        )
+        .unwrap();
+
+        assert_eq!(
+            decomposition.metadata(),
+            &[
+                Some(Pos::Verb),
+                Some(Pos::Noun("neuter")),
+                Some(Pos::Noun("neuter"))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_metadata_missing_without_lexicon() {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        let decomposition =
+            decompound_headed("Hausboot", &is_valid_single_word, Opt::TRY_TITLECASE_SUFFIX)
+                .unwrap();
+
+        assert_eq!(decomposition.metadata(), &[None, None]);
+        assert_eq!(decomposition.compound_metadata(), None);
+    }
+
+    #[test]
+    fn test_metadata_of_lemmas() {
+        let lemmatizer = |w: &str| w.strip_suffix('s').map(ToOwned::to_owned);
+
+        let decomposition = decompound_with_metadata(
+            "Kindergartens",
+            &lexicon,
+            &noun_head,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_lemmatizer(&lemmatizer),
+        )
+        .unwrap();
+
+        assert_eq!(decomposition.head().lemma(), Some("Garten"));
+        assert_eq!(
+            decomposition.compound_metadata(),
+            Some(&Pos::Noun("neuter"))
+        );
+    }
+
+    #[test]
+    fn test_metadata_lexicon_called_once_per_form() {
+        let calls = RefCell::new(Vec::new());
+        let counting_lexicon = |w: &str| {
+            calls.borrow_mut().push(w.to_owned());
+            lexicon(w)
+        };
+
+        let _ = decompound_with_metadata(
+            "Fahrradschloss",
+            &counting_lexicon,
+            &noun_head,
+            Opt::TRY_TITLECASE_SUFFIX,
+        );
+
+        let mut calls = calls.into_inner();
+        let len = calls.len();
+        calls.sort();
+        calls.dedup();
+        assert_eq!(calls.len(), len);
+    }
+
+    #[rstest]
+    #[case(SearchStrategy::Exhaustive)]
+    #[case(SearchStrategy::GreedyLongestPrefix)]
+    #[case(SearchStrategy::GreedyLongestSuffix)]
+    #[case(SearchStrategy::Beam { width: 2 })]
+    fn test_metadata_rules_all_strategies(#[case] strategy: SearchStrategy) {
+        let decomposition = decompound_with_metadata(
+            "Radfahrbahn",
+            &lexicon,
+            &noun_head,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_strategy(strategy),
+        )
+        .unwrap();
+
+        assert_eq!(decomposition.head().form(), "Bahn");
+        assert_eq!(
+            decomposition.compound_metadata(),
+            Some(&Pos::Noun("feminine"))
+        );
+    }
+}
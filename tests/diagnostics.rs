@@ -0,0 +1,205 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::Cell,
+        sync::atomic::{AtomicBool, Ordering},
+    };
+
+    use decompound::{
+        decompound, decompound_with_diagnostics, DecompositionConfig as Config, DecompositionError,
+        DecompositionError::*, DecompositionOptions as Opt,
+    };
+    use rstest::rstest;
+
+    const WORDS: &[&str] = &["Haus", "Boot", "Bootsmann", "bed", "room", "error", "Dampf"];
+
+    fn lemmatizer(w: &str) -> Option<String> {
+        (w == "Häuser").then(|| "Haus".to_owned())
+    }
+
+    #[rstest]
+    #[case(
+        "Hausxboot",
+        Config::new(Opt::TRY_TITLECASE_SUFFIX),
+        NothingValid,
+        Some("Haus"),
+        None,
+        4
+    )]
+    #[case(
+        "Hausbootx",
+        Config::new(Opt::TRY_TITLECASE_SUFFIX),
+        NothingValid,
+        Some("Haus"),
+        None,
+        8
+    )]
+    #[case(
+        "xHausboot",
+        Config::new(Opt::TRY_TITLECASE_SUFFIX),
+        NothingValid,
+        None,
+        None,
+        0
+    )]
+    #[case(
+        "Hausboot",
+        Config::new(Opt::empty()),
+        NothingValid,
+        Some("Haus"),
+        None,
+        4
+    )]
+    // Prefix is the longest, position the furthest
+    #[case(
+        "Bootsmannx",
+        Config::new(Opt::TRY_TITLECASE_SUFFIX),
+        NothingValid,
+        Some("Bootsmann"),
+        None,
+        9
+    )]
+    // Valid by lemma
+    #[case(
+        "Häuserx",
+        Config::new(Opt::TRY_TITLECASE_SUFFIX)
+            .with_lemmatizer(&lemmatizer)
+            .with_lemmatized_modifiers(true),
+        NothingValid,
+        Some("Häuser"),
+        None,
+        7
+    )]
+    // Single words keep their semantics
+    #[case("Haus", Config::new(Opt::TRY_TITLECASE_SUFFIX), SingleWord("Haus".into()), None, None, 0)]
+    // Restrictions on splits apply
+    #[case(
+        "Dampfhaus",
+        Config::new(Opt::TRY_TITLECASE_SUFFIX).with_min_constituent_len(5),
+        NothingValid,
+        None,
+        None,
+        0
+    )]
+    // Decomposed, but rejected
+    #[case("Hausboot", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_max_constituents(1), NothingValid, Some("Haus"), None, 8)]
+    #[case("bedroom-errorless", Config::new(Opt::SPLIT_HYPHENATED), NothingValid, Some("error"), Some((1, "errorless")), 13)]
+    #[case("bedxroom-error", Config::new(Opt::SPLIT_HYPHENATED), NothingValid, Some("bed"), Some((0, "bedxroom")), 3)]
+    #[case("bed-room-x", Config::new(Opt::SPLIT_HYPHENATED), NothingValid, None, Some((2, "x")), 9)]
+    // Not split on hyphens
+    #[case(
+        "bedroom-error",
+        Config::new(Opt::empty()),
+        NothingValid,
+        Some("bed"),
+        None,
+        7
+    )]
+    // Positions are in the original word
+    #[case("Haus\u{00AD}xboot", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS), NothingValid, Some("Haus"), None, 6)]
+    #[case("bed\u{00AD}room-x\u{00AD}y", Config::new(Opt::SPLIT_HYPHENATED | Opt::BOUNDARY_HINTS), NothingValid, None, Some((1, "x\u{00AD}y")), 10)]
+    fn test_diagnostics(
+        #[case] word: &str,
+        #[case] config: Config,
+        #[case] error: DecompositionError,
+        #[case] longest_valid_prefix: Option<&str>,
+        #[case] failing_segment: Option<(usize, &str)>,
+        #[case] furthest_position: usize,
+    ) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+        let options = config.options().clone();
+
+        let diagnosed =
+            decompound_with_diagnostics(word, &is_valid_single_word, config.clone()).unwrap_err();
+
+        assert_eq!(
+            decompound(word, &is_valid_single_word, config),
+            Err(diagnosed.error().clone())
+        );
+        assert_eq!(diagnosed.error(), &error);
+
+        let diagnostics = diagnosed.diagnostics();
+        assert_eq!(diagnostics.longest_valid_prefix(), longest_valid_prefix);
+        assert_eq!(diagnostics.failing_segment(), failing_segment);
+        assert_eq!(diagnostics.furthest_position(), furthest_position);
+        assert_eq!(diagnostics.options(), &options);
+    }
+
+    #[test]
+    fn test_diagnostics_success() {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        assert_eq!(
+            decompound_with_diagnostics(
+                "Hausboot",
+                &is_valid_single_word,
+                Opt::TRY_TITLECASE_SUFFIX
+            ),
+            Ok(vec!["Haus".to_owned(), "Boot".to_owned()])
+        );
+    }
+
+    #[test]
+    fn test_diagnostics_single_search() {
+        let calls = Cell::new(0);
+        let is_valid_single_word = |w: &str| {
+            calls.set(calls.get() + 1);
+            WORDS.contains(&w)
+        };
+
+        let _ = decompound(
+            "Hausbootx",
+            &is_valid_single_word,
+            Opt::TRY_TITLECASE_SUFFIX,
+        );
+        let undiagnosed = calls.replace(0);
+
+        let _ = decompound_with_diagnostics(
+            "Hausbootx",
+            &is_valid_single_word,
+            Opt::TRY_TITLECASE_SUFFIX,
+        );
+        assert_eq!(calls.get(), undiagnosed);
+    }
+
+    #[test]
+    fn test_diagnostics_cancelled() {
+        let cancelled = AtomicBool::new(false);
+        let is_valid_single_word = |w: &str| {
+            // Cancelled only after finding both constituents.
+            if w == "Boot" {
+                cancelled.store(true, Ordering::Relaxed);
+            }
+            WORDS.contains(&w)
+        };
+
+        let diagnosed = decompound_with_diagnostics(
+            "Hausbootx",
+            &is_valid_single_word,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_cancellation_flag(&cancelled),
+        )
+        .unwrap_err();
+
+        assert_eq!(diagnosed.error(), &BudgetExhausted(None));
+        assert_eq!(diagnosed.diagnostics().longest_valid_prefix(), Some("Haus"));
+        assert_eq!(diagnosed.diagnostics().furthest_position(), 8);
+    }
+
+    #[test]
+    fn test_diagnostics_display() {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        let diagnosed = decompound_with_diagnostics(
+            "bedroom-errorless",
+            &is_valid_single_word,
+            Opt::SPLIT_HYPHENATED,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            diagnosed.to_string(),
+            "No valid decomposition found (reached byte 13, in segment 1 'errorless', longest valid prefix 'error')"
+        );
+        assert_eq!(DecompositionError::from(diagnosed), NothingValid);
+    }
+}
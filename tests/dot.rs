@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use decompound::{
+        decompound, search_space_dot, DecompositionConfig as Config, DecompositionOptions as Opt,
+        SearchStats,
+    };
+    use rstest::rstest;
+
+    const WORDS: &[&str] = &["Haus", "Boot", "bed", "room", "a\"b"];
+
+    const ACCEPTED: &str = "color=blue, fontcolor=blue, penwidth=2";
+    const REJECTED: &str = "color=gray, fontcolor=gray";
+    const REJECTED_TITLECASE: &str = "color=gray, fontcolor=gray, style=dashed";
+
+    fn edge(from: usize, to: usize, label: &str, attributes: &str) -> String {
+        format!("    {from} -> {to} [label=\"{label}\", {attributes}];")
+    }
+
+    #[rstest]
+    #[case("Hausboot", Config::new(Opt::TRY_TITLECASE_SUFFIX), vec![edge(0, 4, "Haus", ACCEPTED), edge(4, 8, "Boot", ACCEPTED), edge(4, 8, "boot", REJECTED), edge(4, 7, "Boo", REJECTED_TITLECASE), edge(0, 3, "Hau", REJECTED)])]
+    // Nothing accepted
+    #[case("Hausboot", Config::new(Opt::empty()), vec![edge(0, 4, "Haus", ""), edge(4, 8, "boot", REJECTED), edge(0, 8, "Hausboot", REJECTED)])]
+    #[case("Haus", Config::new(Opt::empty()), vec![edge(0, 4, "Haus", ACCEPTED)])]
+    // Nodes are numbered by chars of the whole word
+    #[case("bedroom-Hausboot", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED), vec![edge(0, 3, "bed", ACCEPTED), edge(3, 7, "room", ACCEPTED), edge(8, 12, "Haus", ACCEPTED), edge(12, 16, "Boot", ACCEPTED)])]
+    #[case("Haus\u{00AD}boot", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS), vec![edge(0, 4, "Haus", ACCEPTED), edge(4, 8, "Boot", ACCEPTED)])]
+    #[case("a\"bHaus", Config::new(Opt::TRY_TITLECASE_SUFFIX), vec![edge(0, 3, "a\\\"b", ACCEPTED), edge(3, 7, "Haus", ACCEPTED)])]
+    fn test_search_space_dot(
+        #[case] word: &str,
+        #[case] config: Config,
+        #[case] expected_edges: Vec<String>,
+    ) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        let dot = search_space_dot(word, &is_valid_single_word, config);
+
+        assert!(dot.starts_with("digraph decomposition {\n"));
+        assert!(dot.ends_with("}\n"));
+        for expected in expected_edges {
+            let expected = expected.replace(", ]", "]");
+            assert!(dot.contains(&expected), "{expected} not in:\n{dot}");
+        }
+    }
+
+    #[test]
+    fn test_search_space_dot_nodes() {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        let dot = search_space_dot("Bäume", &is_valid_single_word, Opt::empty());
+
+        assert!(dot.contains("    label=\"Bäume\";\n"));
+        for i in 0..=5 {
+            assert!(dot.contains(&format!("    {i};\n")));
+        }
+        assert!(!dot.contains("    6;\n"));
+    }
+
+    #[test]
+    fn test_search_space_dot_single_search() {
+        let calls = Cell::new(0);
+        let is_valid_single_word = |w: &str| {
+            calls.set(calls.get() + 1);
+            WORDS.contains(&w)
+        };
+
+        let _ = decompound(
+            "Hausbootx",
+            &is_valid_single_word,
+            Opt::TRY_TITLECASE_SUFFIX,
+        );
+        let undrawn = calls.replace(0);
+
+        // Observers configured see the search, too.
+        let stats = SearchStats::new();
+        let _ = search_space_dot(
+            "Hausbootx",
+            &is_valid_single_word,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_observer(&stats),
+        );
+        assert_eq!(calls.get(), undrawn);
+        assert_eq!(stats.validator_calls(), undrawn);
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests {
+    use decompound::{
+        decompound, decompound_spans, DecompositionConfig as Config, DecompositionError,
+        DecompositionError::*, DecompositionOptions as Opt, Exceptions,
+    };
+    use rstest::{fixture, rstest};
+
+    type DecompositionTestResult<'a> = Result<Vec<&'a str>, DecompositionError>;
+
+    const WORDS: &[&str] = &[
+        "Entnahme",
+        "Stelle",
+        "St",
+        "Elle",
+        "Urin",
+        "Instinkt",
+        "stinkt",
+        "Forschung",
+        "Kinder",
+        "Garten",
+        "Kindergarten",
+        "Fest",
+        "Zelt",
+        "Festzelt",
+    ];
+
+    #[fixture]
+    fn exceptions() -> Exceptions {
+        Exceptions::new()
+            .with_denied_constituent("St")
+            .with_undecomposable_word("Kindergarten")
+            .with_fixed_split("Urinstinkt", ["Ur", "Instinkt"])
+            // Ignored, not spelling out the word
+            .with_fixed_split("Festzelt", ["Fest", "Zel"])
+            // Ignored, not spelling out the word, despite the same length
+            .with_fixed_split("Zeltfest", ["Xy", "Zzzzzz"])
+            // Ignored, not an actual split
+            .with_fixed_split("Forschung", ["Forschung"])
+    }
+
+    #[rstest]
+    // Denied constituents
+    #[case("Entnahmestelle", Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER, Ok(vec!["Entnahme", "Stelle"]))]
+    #[case("St", Opt::TRY_TITLECASE_SUFFIX, Err(NothingValid))]
+    #[case("Stelle", Opt::TRY_TITLECASE_SUFFIX, Err(SingleWord("Stelle".into())))]
+    // Undecomposable words
+    #[case("Kindergarten", Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER, Err(SingleWord("Kindergarten".into())))]
+    #[case("Kindergartenfest", Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER, Ok(vec!["Kinder", "Garten", "Fest"]))]
+    #[case("Festkindergarten", Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER, Ok(vec!["Fest", "Kindergarten"]))]
+    #[case("Kindergarten-Fest", Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER | Opt::SPLIT_HYPHENATED, Ok(vec!["Kindergarten", "Fest"]))]
+    // Fixed splits
+    #[case("Urinstinkt", Opt::TRY_TITLECASE_SUFFIX, Ok(vec!["Ur", "Instinkt"]))]
+    #[case("Urinstinktforschung", Opt::TRY_TITLECASE_SUFFIX, Ok(vec!["Urin", "stinkt", "Forschung"]))]
+    #[case("Kinderurinstinkt", Opt::TRY_TITLECASE_SUFFIX, Ok(vec!["Kinder", "Ur", "Instinkt"]))]
+    #[case("Kinder-Urinstinkt", Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED, Ok(vec!["Kinder", "Ur", "Instinkt"]))]
+    #[case("Festzelt", Opt::TRY_TITLECASE_SUFFIX, Ok(vec!["Fest", "Zelt"]))]
+    #[case("Zeltfest", Opt::TRY_TITLECASE_SUFFIX, Ok(vec!["Zelt", "Fest"]))]
+    #[case("Kinderzeltfest", Opt::TRY_TITLECASE_SUFFIX, Ok(vec!["Kinder", "Zelt", "Fest"]))]
+    #[case("Forschung", Opt::TRY_TITLECASE_SUFFIX, Err(SingleWord("Forschung".into())))]
+    fn test_exceptions(
+        exceptions: Exceptions,
+        #[case] word: &str,
+        #[case] options: Opt,
+        #[case] expected: DecompositionTestResult,
+    ) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        assert_eq!(
+            decompound(
+                word,
+                &is_valid_single_word,
+                Config::new(options).with_exceptions(&exceptions)
+            ),
+            expected.map(|v| v.into_iter().map(ToOwned::to_owned).collect())
+        );
+    }
+
+    #[rstest]
+    fn test_fixed_split_spans(exceptions: Exceptions) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        let word = "Kinderurinstinkt";
+        let constituents = decompound_spans(
+            word,
+            &is_valid_single_word,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_exceptions(&exceptions),
+        )
+        .unwrap();
+
+        assert_eq!(
+            constituents
+                .iter()
+                .map(|c| &word[c.span()])
+                .collect::<Vec<_>>(),
+            vec!["Kinder", "ur", "instinkt"]
+        );
+    }
+}
@@ -0,0 +1,168 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::RefCell,
+        error::Error,
+        fmt,
+        sync::atomic::{AtomicBool, Ordering},
+    };
+
+    use decompound::{
+        decompound, try_decompound, DecompositionConfig as Config,
+        DecompositionError::{BudgetExhausted, NothingValid, SingleWord},
+        DecompositionOptions as Opt, SearchStrategy,
+        TryDecompositionError::{self, Decomposition, Lexicon},
+    };
+    use rstest::rstest;
+
+    const WORDS: &[&str] = &["Haus", "Boot", "Steg", "Lehrer", "Lehrerinnen", "Zimmer"];
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct LookupError(String);
+
+    impl fmt::Display for LookupError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "cannot look up '{}'", self.0)
+        }
+    }
+
+    impl Error for LookupError {}
+
+    /// Fails for all words in `failing`, otherwise looks up [`WORDS`].
+    fn lexicon<'f>(failing: &'f [&'f str]) -> impl Fn(&str) -> Result<bool, LookupError> + 'f {
+        move |w| {
+            if failing.contains(&w) {
+                Err(LookupError(w.to_owned()))
+            } else {
+                Ok(WORDS.contains(&w))
+            }
+        }
+    }
+
+    fn lookup_error(w: &str) -> TryDecompositionError<LookupError> {
+        Lexicon(LookupError(w.to_owned()))
+    }
+
+    #[rstest]
+    #[case("Hausboot", Opt::TRY_TITLECASE_SUFFIX, &[], Ok(vec!["Haus", "Boot"]))]
+    #[case("Hausbootsteg", Opt::TRY_TITLECASE_SUFFIX, &[], Ok(vec!["Haus", "Boot", "Steg"]))]
+    #[case("Haus", Opt::TRY_TITLECASE_SUFFIX, &[], Err(Decomposition(SingleWord("Haus".into()))))]
+    #[case("Hausxboot", Opt::TRY_TITLECASE_SUFFIX, &[], Err(Decomposition(NothingValid)))]
+    // Failures for words never looked up do not matter.
+    #[case("Hausboot", Opt::TRY_TITLECASE_SUFFIX, &["Steg"], Ok(vec!["Haus", "Boot"]))]
+    #[case("Hausbootsteg", Opt::TRY_TITLECASE_SUFFIX, &["Steg"], Err(lookup_error("Steg")))]
+    #[case("Hausboot", Opt::TRY_TITLECASE_SUFFIX, &["Boot"], Err(lookup_error("Boot")))]
+    #[case("Haus", Opt::TRY_TITLECASE_SUFFIX, &["Haus"], Err(lookup_error("Haus")))]
+    // Only the first error is kept.
+    #[case("Hausboot", Opt::TRY_TITLECASE_SUFFIX, &["H", "Boot"], Err(lookup_error("H")))]
+    #[case("Haus-Boot", Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED, &[], Ok(vec!["Haus", "Boot"]))]
+    #[case("Haus-Boot", Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED, &["Boot"], Err(lookup_error("Boot")))]
+    #[case("LehrerInnenzimmer", Opt::TRY_TITLECASE_SUFFIX | Opt::GENDER_INCLUSIVE, &[], Ok(vec!["LehrerInnen", "Zimmer"]))]
+    #[case("LehrerInnenzimmer", Opt::TRY_TITLECASE_SUFFIX | Opt::GENDER_INCLUSIVE, &["Lehrer"], Err(lookup_error("Lehrer")))]
+    fn test_try_decompound(
+        #[case] word: &str,
+        #[case] options: Opt,
+        #[case] failing: &[&str],
+        #[case] expected: Result<Vec<&str>, TryDecompositionError<LookupError>>,
+    ) {
+        assert_eq!(
+            try_decompound(word, &lexicon(failing), options),
+            expected.map(|v| v.into_iter().map(ToOwned::to_owned).collect())
+        );
+    }
+
+    #[rstest]
+    #[case(SearchStrategy::Exhaustive)]
+    #[case(SearchStrategy::GreedyLongestPrefix)]
+    #[case(SearchStrategy::GreedyLongestSuffix)]
+    #[case(SearchStrategy::Beam { width: 2 })]
+    fn test_no_lookups_after_failure(#[case] strategy: SearchStrategy) {
+        let calls = RefCell::new(Vec::new());
+        let is_valid_single_word = |w: &str| {
+            calls.borrow_mut().push(w.to_owned());
+            lexicon(&["Boot"])(w)
+        };
+
+        let result = try_decompound(
+            "Hausbootsteg",
+            &is_valid_single_word,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_strategy(strategy),
+        );
+
+        assert_eq!(result, Err(lookup_error("Boot")));
+        assert_eq!(calls.into_inner().last().map(String::as_str), Some("Boot"));
+    }
+
+    #[test]
+    fn test_failure_over_budget_exhaustion() {
+        let result = try_decompound(
+            "Hausbootsteg",
+            &lexicon(&["Boot"]),
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_max_validator_calls(1000),
+        );
+
+        assert_eq!(result, Err(lookup_error("Boot")));
+    }
+
+    #[test]
+    fn test_budget_exhaustion_without_failure() {
+        let result = try_decompound(
+            "Hausbootsteg",
+            &lexicon(&[]),
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_max_validator_calls(1),
+        );
+
+        assert!(matches!(result, Err(Decomposition(BudgetExhausted(_)))));
+    }
+
+    #[test]
+    fn test_cancellation_flag_untouched() {
+        let flag = AtomicBool::new(false);
+
+        let result = try_decompound(
+            "Hausboot",
+            &lexicon(&["Boot"]),
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_cancellation_flag(&flag),
+        );
+
+        assert_eq!(result, Err(lookup_error("Boot")));
+        assert!(!flag.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn test_question_mark_on_both_errors() {
+        fn both(word: &str) -> Result<Vec<String>, TryDecompositionError<LookupError>> {
+            let infallible = decompound(word, &|w| WORDS.contains(&w), Opt::TRY_TITLECASE_SUFFIX)?;
+            let fallible = try_decompound(word, &lexicon(&[]), Opt::TRY_TITLECASE_SUFFIX)?;
+            assert_eq!(infallible, fallible);
+
+            Ok(fallible)
+        }
+
+        assert_eq!(both("Hausboot"), Ok(vec!["Haus".into(), "Boot".into()]));
+        assert_eq!(both("Haus"), Err(Decomposition(SingleWord("Haus".into()))));
+    }
+
+    #[test]
+    fn test_lexicon_error_display_and_source() {
+        let error = lookup_error("Boot");
+
+        assert_eq!(
+            error.to_string(),
+            "Lexicon lookup failed: cannot look up 'Boot'"
+        );
+        assert_eq!(
+            error.source().map(ToString::to_string),
+            Some("cannot look up 'Boot'".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_decomposition_error_display_and_source() {
+        let error: TryDecompositionError<LookupError> = Decomposition(NothingValid);
+
+        assert_eq!(error.to_string(), "No valid decomposition found");
+        assert!(error.source().is_none());
+        assert_eq!(TryDecompositionError::from(NothingValid), error);
+    }
+}
@@ -0,0 +1,158 @@
+#[cfg(test)]
+mod tests {
+    use decompound::{
+        decompound, DecompositionConfig as Config, DecompositionError, DecompositionOptions as Opt,
+        FrequencyLexicon, SearchStrategy,
+    };
+    use rstest::{fixture, rstest};
+
+    type DecompositionTestResult<'a> = Result<Vec<&'a str>, DecompositionError>;
+
+    #[fixture]
+    fn frequencies() -> FrequencyLexicon {
+        FrequencyLexicon::parse(
+            "Stau\t2000\n\
+             Becken\t1500\n\
+             Staub\t900\n\
+             Ecken\t300\n\
+             Baum\t5000\n\
+             Ast\t1200\n\
+             Bau\t3000\n\
+             Mast\t200\n\
+             Wach\t50\n\
+             Wachs\t400\n\
+             Stube\t100\n\
+             Tube\t600\n\
+             Haus\t9000\n\
+             Boot\t700\n\
+             Hausboot\t20\n\
+             Selten\t0\n",
+        )
+    }
+
+    #[rstest]
+    #[case("Stau\t2", vec![("Stau", 2)])]
+    #[case("Stau\t2\nStau\t3", vec![("Stau", 5)])]
+    #[case("Stau\t2\r\nBecken\t1\r\n", vec![("Stau", 2), ("Becken", 1)])]
+    #[case("\nStau 2\nStau\tmany\n\nBecken\t1", vec![("Becken", 1)])]
+    #[case("Stau\t18446744073709551615\nStau\t1", vec![("Stau", u64::MAX)])]
+    fn test_parse(#[case] input: &str, #[case] expected: Vec<(&str, u64)>) {
+        let frequencies = FrequencyLexicon::parse(input);
+
+        assert_eq!(frequencies.len(), expected.len());
+        for (word, count) in expected {
+            assert!(frequencies.contains(word));
+            assert_eq!(frequencies.frequency(word), count);
+        }
+    }
+
+    #[rstest]
+    #[case(&["Stau", "Becken"], (2000.0f64 * 1500.0).sqrt())]
+    #[case(&["Staub", "Ecken"], (900.0f64 * 300.0).sqrt())]
+    #[case(&["Haus"], 9000.0)]
+    #[case(&["Haus", "Unbekannt"], 0.0)]
+    #[case(&["Haus", "Selten"], 0.0)]
+    #[case(&[], 0.0)]
+    fn test_geometric_mean(
+        frequencies: FrequencyLexicon,
+        #[case] constituents: &[&str],
+        #[case] expected: f64,
+    ) {
+        assert!((frequencies.geometric_mean(constituents) - expected).abs() < 1e-6);
+    }
+
+    #[rstest]
+    #[case("Staubecken", Opt::TRY_TITLECASE_SUFFIX, Ok(vec!["Stau", "Becken"]))]
+    #[case("Baumast", Opt::TRY_TITLECASE_SUFFIX, Ok(vec!["Baum", "Ast"]))]
+    #[case("Wachstube", Opt::TRY_TITLECASE_SUFFIX, Ok(vec!["Wachs", "Tube"]))]
+    // Fewer constituents are not preferred per se
+    #[case("Hausbootbau", Opt::TRY_TITLECASE_SUFFIX, Ok(vec!["Haus", "Boot", "Bau"]))]
+    #[case("Hausbootbau", Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER, Ok(vec!["Haus", "Boot", "Bau"]))]
+    #[case("Baumast-Wachstube", Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED, Ok(vec!["Baum", "Ast", "Wachs", "Tube"]))]
+    fn test_decompound_with_frequencies(
+        frequencies: FrequencyLexicon,
+        #[case] word: &str,
+        #[case] options: Opt,
+        #[case] expected: DecompositionTestResult,
+    ) {
+        let is_valid_single_word = |w: &str| frequencies.contains(w);
+
+        assert_eq!(
+            decompound(
+                word,
+                &is_valid_single_word,
+                Config::new(options).with_frequencies(&frequencies)
+            ),
+            expected.map(|v| v.into_iter().map(ToOwned::to_owned).collect())
+        );
+    }
+
+    #[rstest]
+    #[case("aaabbcc", Ok(vec!["aaa", "bb", "cc"]))]
+    #[case("aaabc", Ok(vec!["aaa", "bc"]))]
+    // The word itself competes, and is not decomposed if more frequent
+    #[case("aaac", Err(DecompositionError::SingleWord("aaac".to_owned())))]
+    #[case("aaacc", Ok(vec!["aaa", "cc"]))]
+    fn test_decompound_with_frequencies_ranks_complete_decompositions(
+        #[case] word: &str,
+        #[case] expected: DecompositionTestResult,
+    ) {
+        // Alone, `bbcc` is decomposed into single letters rather than `bb`, `cc`, but
+        // not as part of `aaabbcc`.
+        let frequencies = FrequencyLexicon::parse(
+            "aaa\t10000\n\
+             bb\t100\n\
+             cc\t100\n\
+             b\t110\n\
+             bc\t110\n\
+             c\t110\n\
+             aaac\t5000\n\
+             aaacc\t50\n",
+        );
+        let is_valid_single_word = |w: &str| frequencies.contains(w);
+
+        assert_eq!(
+            decompound(
+                word,
+                &is_valid_single_word,
+                Config::new(Opt::empty()).with_frequencies(&frequencies)
+            ),
+            expected.map(|v| v.into_iter().map(ToOwned::to_owned).collect())
+        );
+    }
+
+    #[rstest]
+    fn test_beam_with_frequencies(frequencies: FrequencyLexicon) {
+        let is_valid_single_word = |w: &str| frequencies.contains(w);
+
+        assert_eq!(
+            decompound(
+                "Baumast",
+                &is_valid_single_word,
+                Config::new(Opt::TRY_TITLECASE_SUFFIX)
+                    .with_strategy(SearchStrategy::Beam { width: 4 })
+                    .with_frequencies(&frequencies)
+            )
+            .unwrap(),
+            vec!["Baum", "Ast"]
+        );
+    }
+
+    #[rstest]
+    fn test_custom_scorer() {
+        let is_valid_single_word = |w: &str| ["Haus", "Boot", "Bau", "Hausboot"].contains(&w);
+        // Prefer as many constituents as possible, like `SHATTER`
+        #[allow(clippy::cast_precision_loss)]
+        let most = |constituents: &[&str]| constituents.len() as f64;
+
+        assert_eq!(
+            decompound(
+                "Hausbootbau",
+                &is_valid_single_word,
+                Config::new(Opt::TRY_TITLECASE_SUFFIX).with_scorer(&most)
+            )
+            .unwrap(),
+            vec!["Haus", "Boot", "Bau"]
+        );
+    }
+}
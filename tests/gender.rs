@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use decompound::{
+        decompound, DecompositionError, DecompositionError::*, DecompositionOptions as Opt,
+        GenderMarker,
+    };
+    use rstest::rstest;
+
+    type DecompositionTestResult<'a> = Result<Vec<&'a str>, DecompositionError>;
+
+    const WORDS: &[&str] = &[
+        "Lehrer",
+        "Lehrerin",
+        "Lehrerinnen",
+        "Bürger",
+        "Bürgerinnen",
+        "Beteiligung",
+        "Zimmer",
+        "Innen",
+        // Base only, feminine form missing
+        "Arzt",
+        "Ärztinnen",
+        // Feminine form only, base missing
+        "Kolleginnen",
+    ];
+
+    #[rstest]
+    #[case("Lehrer*innen", Opt::empty(), Err(NothingValid))]
+    #[case("Lehrer*innen", Opt::GENDER_INCLUSIVE, Err(SingleWord("Lehrer*innen".into())))]
+    #[case("Lehrer:innen", Opt::GENDER_INCLUSIVE, Err(SingleWord("Lehrer:innen".into())))]
+    #[case("Lehrer_innen", Opt::GENDER_INCLUSIVE, Err(SingleWord("Lehrer_innen".into())))]
+    #[case("Lehrer*in", Opt::GENDER_INCLUSIVE, Err(SingleWord("Lehrer*in".into())))]
+    //
+    // Binnen-I: without the option, `Innen` is happily split off
+    #[case("LehrerInnen", Opt::empty(), Ok(vec!["Lehrer", "Innen"]))]
+    #[case("LehrerInnen", Opt::GENDER_INCLUSIVE, Err(SingleWord("LehrerInnen".into())))]
+    #[case("LehrerIn", Opt::GENDER_INCLUSIVE, Err(SingleWord("LehrerIn".into())))]
+    //
+    // Inside of compounds
+    #[case(
+        "Bürger*innenbeteiligung",
+        Opt::TRY_TITLECASE_SUFFIX,
+        Err(NothingValid)
+    )]
+    #[case("Bürger*innenbeteiligung", Opt::TRY_TITLECASE_SUFFIX | Opt::GENDER_INCLUSIVE, Ok(vec!["Bürger*innen", "Beteiligung"]))]
+    #[case("BürgerInnenbeteiligung", Opt::TRY_TITLECASE_SUFFIX | Opt::GENDER_INCLUSIVE, Ok(vec!["BürgerInnen", "Beteiligung"]))]
+    #[case("Lehrer:innenzimmer", Opt::TRY_TITLECASE_SUFFIX | Opt::GENDER_INCLUSIVE, Ok(vec!["Lehrer:innen", "Zimmer"]))]
+    #[case("Lehrer:innenzimmer", Opt::all(), Ok(vec!["Lehrer:innen", "Zimmer"]))]
+    #[case("Zimmerlehrer*innen", Opt::TRY_TITLECASE_SUFFIX | Opt::GENDER_INCLUSIVE, Ok(vec!["Zimmer", "Lehrer*innen"]))]
+    #[case("Zimmerlehrer:innen", Opt::TRY_TITLECASE_SUFFIX | Opt::GENDER_INCLUSIVE, Ok(vec!["Zimmer", "Lehrer:innen"]))]
+    #[case("Zimmerlehrer_in", Opt::TRY_TITLECASE_SUFFIX | Opt::GENDER_INCLUSIVE, Ok(vec!["Zimmer", "Lehrer_in"]))]
+    // Titlecasing must not lose the Binnen-I of non-initial constituents.
+    #[case("ZimmerlehrerInnen", Opt::TRY_TITLECASE_SUFFIX | Opt::GENDER_INCLUSIVE, Ok(vec!["Zimmer", "LehrerInnen"]))]
+    #[case("ZimmerlehrerIn", Opt::TRY_TITLECASE_SUFFIX | Opt::GENDER_INCLUSIVE, Ok(vec!["Zimmer", "LehrerIn"]))]
+    // Without the option, merely a titlecased feminine form
+    #[case("ZimmerlehrerInnen", Opt::TRY_TITLECASE_SUFFIX, Ok(vec!["Zimmer", "Lehrerinnen"]))]
+    #[case("ZimmerbürgerInnenbeteiligung", Opt::TRY_TITLECASE_SUFFIX | Opt::GENDER_INCLUSIVE, Ok(vec!["Zimmer", "BürgerInnen", "Beteiligung"]))]
+    // Base and feminine form are validated for non-initial constituents, too.
+    #[case("ZimmerärztInnen", Opt::TRY_TITLECASE_SUFFIX | Opt::GENDER_INCLUSIVE, Err(NothingValid))]
+    #[case("ZimmerkollegInnen", Opt::TRY_TITLECASE_SUFFIX | Opt::GENDER_INCLUSIVE, Err(NothingValid))]
+    #[case("Zimmerkolleg*innen", Opt::TRY_TITLECASE_SUFFIX | Opt::GENDER_INCLUSIVE, Err(NothingValid))]
+    //
+    // Feminine form not in dictionary
+    #[case("Arzt*innen", Opt::GENDER_INCLUSIVE, Err(NothingValid))]
+    // Marker without feminine suffix
+    #[case("Lehrer*", Opt::GENDER_INCLUSIVE, Err(NothingValid))]
+    #[case("*innen", Opt::GENDER_INCLUSIVE, Err(NothingValid))]
+    fn test_decompound_gender_inclusive(
+        #[case] word: &str,
+        #[case] options: Opt,
+        #[case] expected: DecompositionTestResult,
+    ) {
+        assert_eq!(
+            decompound(word, &|w| WORDS.contains(&w), options),
+            expected.map(|v| v.into_iter().map(String::from).collect())
+        );
+    }
+
+    #[rstest]
+    #[case("Lehrer*innen", Some(("Lehrer", GenderMarker::Asterisk, "innen")))]
+    #[case("Lehrer:in", Some(("Lehrer", GenderMarker::Colon, "in")))]
+    #[case("Lehrer_innen", Some(("Lehrer", GenderMarker::Underscore, "innen")))]
+    #[case("LehrerInnen", Some(("Lehrer", GenderMarker::BinnenI, "Innen")))]
+    #[case("LehrerIn", Some(("Lehrer", GenderMarker::BinnenI, "In")))]
+    #[case("Lehrerinnen", None)]
+    #[case("Innen", None)]
+    #[case("*innen", None)]
+    #[case("LEHRERINNEN", None)]
+    #[case("", None)]
+    fn test_gender_marker_split(
+        #[case] word: &str,
+        #[case] expected: Option<(&str, GenderMarker, &str)>,
+    ) {
+        assert_eq!(GenderMarker::split(word), expected);
+    }
+}
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use decompound::{
+        decompound, decompound_spans, DecompositionConfig as Config, DecompositionError,
+        DecompositionError::*, DecompositionOptions as Opt,
+    };
+    use rstest::rstest;
+
+    type DecompositionTestResult<'a> = Result<Vec<&'a str>, DecompositionError>;
+
+    const SHY: &str = "\u{00AD}";
+    const ZWNJ: &str = "\u{200C}";
+
+    const WORDS: &[&str] = &[
+        "Auf", "Lage", "Auflage", "Wach", "Stube", "Wachs", "Tube", "Haus", "Boot",
+    ];
+
+    #[rstest]
+    #[case(format!("Auf{SHY}lage"), Config::new(Opt::TRY_TITLECASE_SUFFIX), Err(NothingValid))]
+    #[case(format!("Auf{SHY}lage"), Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS), Ok(vec!["Auf", "Lage"]))]
+    #[case(format!("Auf{ZWNJ}lage"), Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS), Ok(vec!["Auf", "Lage"]))]
+    #[case(format!("Auf{SHY}{ZWNJ}lage"), Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS), Ok(vec!["Auf", "Lage"]))]
+    #[case(format!("Auf{SHY}lage"), Config::new(Opt::TRY_TITLECASE_SUFFIX).with_mandatory_boundary_hints(true), Ok(vec!["Auf", "Lage"]))]
+    //
+    // Preferred over whatever would be picked otherwise...
+    #[case("Wachstube".into(), Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS), Ok(vec!["Wach", "Stube"]))]
+    #[case(format!("Wachs{SHY}tube"), Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS), Ok(vec!["Wachs", "Tube"]))]
+    #[case(format!("Wach{SHY}stube"), Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS), Ok(vec!["Wach", "Stube"]))]
+    // ... but not forced
+    #[case(format!("Wa{SHY}chstube"), Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS), Ok(vec!["Wach", "Stube"]))]
+    #[case(format!("Wa{SHY}chstube"), Config::new(Opt::TRY_TITLECASE_SUFFIX).with_mandatory_boundary_hints(true), Err(NothingValid))]
+    //
+    // Hints at syllable boundaries do not break anything
+    #[case(format!("Auf{SHY}la{SHY}ge"), Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS), Ok(vec!["Auf", "Lage"]))]
+    #[case(format!("Auf{SHY}la{SHY}ge"), Config::new(Opt::TRY_TITLECASE_SUFFIX).with_mandatory_boundary_hints(true), Ok(vec!["Auf", "Lage"]))]
+    //
+    // No compound without hints when mandatory
+    #[case("Auflage".into(), Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS), Ok(vec!["Auf", "Lage"]))]
+    #[case("Auflage".into(), Config::new(Opt::TRY_TITLECASE_SUFFIX).with_mandatory_boundary_hints(true), Err(SingleWord("Auflage".into())))]
+    #[case(format!("Auf{SHY}lage"), Config::new(Opt::BOUNDARY_HINTS), Err(SingleWord("Auflage".into())))]
+    //
+    #[case(format!("Haus{SHY}boot-Auf{SHY}lage"), Config::new(Opt::all()), Ok(vec!["Haus", "Boot", "Auf", "Lage"]))]
+    #[case(format!("{SHY}Haus{SHY}"), Config::new(Opt::BOUNDARY_HINTS), Err(SingleWord("Haus".into())))]
+    #[case(SHY.into(), Config::new(Opt::BOUNDARY_HINTS), Err(NothingValid))]
+    fn test_decompound_boundary_hints(
+        #[case] word: String,
+        #[case] config: Config,
+        #[case] expected: DecompositionTestResult,
+    ) {
+        assert_eq!(
+            decompound(word, &|w| WORDS.contains(&w), config),
+            expected.map(|v| v.into_iter().map(String::from).collect())
+        );
+    }
+
+    #[rstest]
+    #[case("Hausboot".into(), Opt::TRY_TITLECASE_SUFFIX, vec![("Haus", "Haus"), ("boot", "Boot")])]
+    #[case(format!("Haus{SHY}boot"), Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS, vec![("Haus", "Haus"), ("boot", "Boot")])]
+    #[case(format!("Haus{SHY}{ZWNJ}boot"), Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS, vec![("Haus", "Haus"), ("boot", "Boot")])]
+    #[case(format!("{SHY}Haus{SHY}boot{SHY}"), Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS, vec![("Haus", "Haus"), ("boot", "Boot")])]
+    #[case(format!("Hau{SHY}s{SHY}boot"), Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS, vec![("Hau\u{00AD}s", "Haus"), ("boot", "Boot")])]
+    #[case(format!("Haus-Auf{SHY}lage"), Opt::all(), vec![("Haus", "Haus"), ("Auf", "Auf"), ("lage", "Lage")])]
+    fn test_decompound_spans_map_onto_original(
+        #[case] word: String,
+        #[case] options: Opt,
+        #[case] expected: Vec<(&str, &str)>,
+    ) {
+        let constituents = decompound_spans(&word, &|w| WORDS.contains(&w), options).unwrap();
+
+        assert_eq!(
+            constituents
+                .iter()
+                .map(|c| (&word[c.span()], c.form()))
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+}
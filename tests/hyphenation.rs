@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod tests {
+    use decompound::{
+        decompound, DecompositionConfig as Config, DecompositionError, DecompositionError::*,
+        DecompositionOptions as Opt, HyphenationPatterns,
+    };
+    use rstest::rstest;
+
+    type DecompositionTestResult<'a> = Result<Vec<&'a str>, DecompositionError>;
+
+    /// The example from Liang's thesis, "Word Hy-phen-a-tion by Com-put-er".
+    const LIANG: &str = "hy3ph he2n hena4 hen5at 1na n2at 1tio 2io o2n";
+
+    #[rstest]
+    #[case(LIANG, "hyphenation", vec!["hy", "hyphen"])]
+    #[case(LIANG, "Hyphenation", vec!["Hy", "Hyphen"])]
+    #[case("u1c r1z g1n", "Druckerzeugnis", vec!["Dru", "Drucker", "Druckerzeug"])]
+    // Minimum lengths at the edges
+    #[case("1a", "aaaa", vec!["aa"])]
+    #[case("1a", "aaa", vec![])]
+    #[case("1a", "", vec![])]
+    // Highest value wins, even ones forbid
+    #[case("1a b2a", "abab", vec![])]
+    #[case("1a b2a ab3a", "abab", vec!["ab"])]
+    // Word edges
+    #[case(".a1b", "abab", vec![])]
+    #[case(".ab1a", "abab", vec!["ab"])]
+    #[case("a1b.", "abab", vec![])]
+    #[case("1bab.", "babab", vec!["ba"])]
+    // Multi-byte chars
+    #[case("ö1ä", "äöäö", vec!["äö"])]
+    fn test_hyphenation_points(
+        #[case] patterns: &str,
+        #[case] word: &str,
+        #[case] expected: Vec<&str>,
+    ) {
+        let patterns = HyphenationPatterns::parse(patterns);
+
+        assert_eq!(
+            patterns
+                .hyphenation_points(word)
+                .into_iter()
+                .map(|i| &word[..i])
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(0, 0, vec!["a", "aa", "aaa"])]
+    #[case(1, 1, vec!["a", "aa", "aaa"])]
+    #[case(3, 1, vec!["aaa"])]
+    #[case(1, 3, vec!["a"])]
+    #[case(2, 3, vec![])]
+    fn test_hyphenation_points_min_lengths(
+        #[case] left: usize,
+        #[case] right: usize,
+        #[case] expected: Vec<&str>,
+    ) {
+        let patterns = HyphenationPatterns::parse("1a").with_min_lengths(left, right);
+        let word = "aaaa";
+
+        assert_eq!(
+            patterns
+                .hyphenation_points(word)
+                .into_iter()
+                .map(|i| &word[..i])
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_parse_tex_source() {
+        let patterns = HyphenationPatterns::parse(
+            "% A comment, 1a\n\\patterns{ % another\nu1c r1z\n\n  g1n\n}\n",
+        );
+
+        assert_eq!(patterns.len(), 3);
+        assert_eq!(
+            patterns,
+            HyphenationPatterns::parse("u1c r1z g1n"),
+            "{patterns:?}"
+        );
+    }
+
+    #[test]
+    fn test_from_file() {
+        let path = std::env::temp_dir().join(format!(
+            "decompound-test-hyph-{}.pat.txt",
+            std::process::id()
+        ));
+        std::fs::write(&path, "u1c\nr1z\ng1n\n").unwrap();
+
+        let patterns = HyphenationPatterns::from_file(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(patterns.unwrap(), HyphenationPatterns::parse("u1c r1z g1n"));
+        assert!(HyphenationPatterns::from_file(path.with_extension("missing")).is_err());
+    }
+
+    #[rstest]
+    #[case("Druckerzeugnis", Opt::TRY_TITLECASE_SUFFIX, Ok(vec!["Drucker", "Zeugnis"]))]
+    #[case("Druckerzeugnis", Opt::empty(), Err(NothingValid))]
+    #[case("Druckerzeugnis-Druckerzeugnis", Opt::all(), Ok(vec!["Drucker", "Zeugnis", "Drucker", "Zeugnis"]))]
+    // Split *inside* a syllable not allowed
+    #[case("Druckerei", Opt::TRY_TITLECASE_SUFFIX, Err(SingleWord("Druckerei".into())))]
+    // Boundary hints are always allowed
+    #[case("Druck\u{00AD}erzeugnis", Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS, Ok(vec!["Druck", "Erzeugnis"]))]
+    fn test_decompound_with_hyphenation_patterns(
+        #[case] word: &str,
+        #[case] options: Opt,
+        #[case] expected: DecompositionTestResult,
+    ) {
+        const WORDS: &[&str] = &[
+            "Druck",
+            "Erzeugnis",
+            "Drucker",
+            "Zeugnis",
+            "Druckerei",
+            "Ei",
+        ];
+        let patterns = HyphenationPatterns::parse("u1c r1z g1n");
+
+        assert_eq!(
+            decompound(
+                word,
+                &|w| WORDS.contains(&w),
+                Config::new(options).with_hyphenation_patterns(&patterns)
+            ),
+            expected.map(|v| v.into_iter().map(String::from).collect())
+        );
+    }
+}
@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use decompound::{
+        DecompositionConfig as Config, DecompositionOptions as Opt, Exceptions, Lattice,
+    };
+    use rstest::rstest;
+
+    const WORDS: &[&str] = &[
+        "Wach", "Stube", "Wachs", "Tube", "Haus", "Boot", "Hausboot", "a", "b",
+    ];
+
+    fn forms(path: &[&decompound::LatticeEdge]) -> Vec<String> {
+        path.iter().map(|edge| edge.form().to_owned()).collect()
+    }
+
+    #[rstest]
+    #[case("Wachstube", Config::new(Opt::TRY_TITLECASE_SUFFIX), 2, vec![vec!["Wach", "Stube"], vec!["Wachs", "Tube"]])]
+    #[case("Wachstube", Config::new(Opt::empty()), 0, vec![])]
+    // Includes the single word
+    #[case("Hausboot", Config::new(Opt::TRY_TITLECASE_SUFFIX), 2, vec![vec!["Haus", "Boot"], vec!["Hausboot"]])]
+    #[case("abab", Config::new(Opt::empty()), 1, vec![vec!["a", "b", "a", "b"]])]
+    #[case("abab", Config::new(Opt::empty()).with_min_constituent_len(2), 0, vec![])]
+    #[case("", Config::new(Opt::empty()), 1, vec![vec![]])]
+    #[case("Wachs\u{00AD}tube", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS), 2, vec![vec!["Wach", "Stube"], vec!["Wachs", "Tube"]])]
+    fn test_lattice_paths(
+        #[case] word: &str,
+        #[case] config: Config,
+        #[case] count: usize,
+        #[case] expected: Vec<Vec<&str>>,
+    ) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        let lattice = Lattice::new(word, &is_valid_single_word, config);
+
+        assert_eq!(lattice.count_paths(), count);
+        let mut paths: Vec<Vec<String>> = lattice.paths().iter().map(|p| forms(p)).collect();
+        paths.sort();
+        assert_eq!(paths, expected);
+    }
+
+    #[test]
+    fn test_lattice_edges() {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+        let word = "Wachs\u{00AD}tube";
+
+        let lattice = Lattice::new(
+            word,
+            &is_valid_single_word,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS),
+        );
+
+        assert_eq!(lattice.nodes(), 10);
+        assert_eq!(
+            lattice
+                .edges()
+                .iter()
+                .map(|e| (
+                    e.from(),
+                    e.to(),
+                    e.form(),
+                    &word[e.span()],
+                    e.is_transformed()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (0, 4, "Wach", "Wach", false),
+                (0, 5, "Wachs", "Wachs", false),
+                (1, 2, "a", "a", false),
+                // Spans hints within
+                (4, 9, "Stube", "s\u{00AD}tube", true),
+                (5, 9, "Tube", "tube", true),
+                (7, 8, "b", "b", false),
+            ]
+        );
+        assert_eq!(lattice.edges_from(0).count(), 2);
+        assert_eq!(lattice.edges_from(2).count(), 0);
+    }
+
+    #[test]
+    fn test_lattice_one_call_per_substring() {
+        let calls = Cell::new(0);
+        let is_valid_single_word = |w: &str| {
+            calls.set(calls.get() + 1);
+            WORDS.contains(&w)
+        };
+
+        let lattice = Lattice::new("abab", &is_valid_single_word, Opt::empty());
+
+        // "a", "b", "ab", "ba", "aba", "bab", "abab"
+        assert_eq!(calls.get(), 7);
+        assert_eq!(lattice.edges().len(), 4);
+    }
+
+    #[test]
+    fn test_lattice_denied_constituents() {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+        let exceptions = Exceptions::new().with_denied_constituent("Hausboot");
+
+        let lattice = Lattice::new(
+            "Hausboot",
+            &is_valid_single_word,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_exceptions(&exceptions),
+        );
+
+        assert_eq!(lattice.count_paths(), 1);
+    }
+
+    #[rstest]
+    #[case(|form: &str| form.len() as f64, 8.0, vec!["Hausboot"])]
+    #[case(|_: &str| 1.0, 1.0, vec!["Hausboot"])]
+    #[case(|form: &str| if form == "Hausboot" { 10.0 } else { 1.0 }, 2.0, vec!["Haus", "Boot"])]
+    fn test_lattice_best_path(
+        #[case] cost: fn(&str) -> f64,
+        #[case] total: f64,
+        #[case] expected: Vec<&str>,
+    ) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        let lattice = Lattice::new("Hausboot", &is_valid_single_word, Opt::TRY_TITLECASE_SUFFIX);
+        let (best, path) = lattice.best_path(|edge| cost(edge.form())).unwrap();
+
+        assert_eq!(best, total);
+        assert_eq!(forms(&path), expected);
+    }
+
+    #[test]
+    fn test_lattice_best_path_none() {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        let lattice = Lattice::new(
+            "Hausxboot",
+            &is_valid_single_word,
+            Opt::TRY_TITLECASE_SUFFIX,
+        );
+
+        assert_eq!(lattice.best_path(|_| 1.0), None);
+        assert!(lattice.paths().is_empty());
+    }
+}
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use decompound::{
+        decompound, decompound_spans, DecompositionConfig as Config,
+        DecompositionError::{self, NothingValid, SingleWord},
+        DecompositionOptions as Opt, SearchStrategy,
+    };
+    use rstest::rstest;
+
+    const WORDS: &[&str] = &[
+        "Affen", "Gruppe", "Haus", "Reihe", "Kinder", "Garten", "Gärten",
+    ];
+
+    fn lemmatizer(w: &str) -> Option<String> {
+        match w {
+            "Gruppen" => Some("Gruppe"),
+            "Häusern" | "Häuser" | "häuser" => Some("Haus"),
+            "Gartens" | "Gärten" => Some("Garten"),
+            "Reihen" => Some("Reihen"),
+            "Affe" => Some(""),
+            _ => None,
+        }
+        .map(ToOwned::to_owned)
+    }
+
+    #[rstest]
+    #[case(
+        "Affengruppen",
+        Config::new(Opt::TRY_TITLECASE_SUFFIX),
+        Err(NothingValid)
+    )]
+    #[case("Affengruppen", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_lemmatizer(&lemmatizer), Ok(vec![("Affen", None), ("Gruppen", Some("Gruppe"))]))]
+    // The lemmatizer sees forms as looked up, here titlecased.
+    #[case("Affengruppen", Config::new(Opt::empty()).with_lemmatizer(&lemmatizer), Err(NothingValid))]
+    #[case("Kinderhäusern", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_lemmatizer(&lemmatizer), Ok(vec![("Kinder", None), ("Häusern", Some("Haus"))]))]
+    #[case("Kindergartens", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_lemmatizer(&lemmatizer), Ok(vec![("Kinder", None), ("Gartens", Some("Garten"))]))]
+    // Modifiers only if asked to
+    #[case("Häuserreihe", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_lemmatizer(&lemmatizer), Err(NothingValid))]
+    #[case("Häuserreihe", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_lemmatizer(&lemmatizer).with_lemmatized_modifiers(true), Ok(vec![("Häuser", Some("Haus")), ("Reihe", None)]))]
+    #[case("Kinderhäuserreihe", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_lemmatizer(&lemmatizer).with_lemmatized_modifiers(true), Ok(vec![("Kinder", None), ("Häuser", Some("Haus")), ("Reihe", None)]))]
+    // Lemmas equal to the form or empty are no lemmas.
+    #[case("Affenreihen", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_lemmatizer(&lemmatizer), Err(NothingValid))]
+    #[case("Affegruppe", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_lemmatizer(&lemmatizer).with_lemmatized_modifiers(true), Err(NothingValid))]
+    // Each hyphen-separated part has a final constituent.
+    #[case("Affengruppen-Kinderhäusern", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED).with_lemmatizer(&lemmatizer), Ok(vec![("Affen", None), ("Gruppen", Some("Gruppe")), ("Kinder", None), ("Häusern", Some("Haus"))]))]
+    // Inflected single words
+    #[case("Häusern", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_lemmatizer(&lemmatizer), Err(SingleWord("Häusern".into())))]
+    fn test_lemmatizer(
+        #[case] word: &str,
+        #[case] config: Config,
+        #[case] expected: Result<Vec<(&str, Option<&str>)>, DecompositionError>,
+    ) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        let result = decompound_spans(word, &is_valid_single_word, config);
+
+        match (result, expected) {
+            (Ok(constituents), Ok(expected)) => {
+                let found: Vec<(&str, Option<&str>)> =
+                    constituents.iter().map(|c| (c.form(), c.lemma())).collect();
+                assert_eq!(found, expected);
+            }
+            (result, expected) => assert_eq!(result.map(|_| ()), expected.map(|_| ())),
+        }
+    }
+
+    #[rstest]
+    #[case(SearchStrategy::Exhaustive)]
+    #[case(SearchStrategy::GreedyLongestPrefix)]
+    #[case(SearchStrategy::GreedyLongestSuffix)]
+    #[case(SearchStrategy::Beam { width: 2 })]
+    fn test_lemmatizer_all_strategies(#[case] strategy: SearchStrategy) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        assert_eq!(
+            decompound(
+                "Affengruppen",
+                &is_valid_single_word,
+                Config::new(Opt::TRY_TITLECASE_SUFFIX)
+                    .with_lemmatizer(&lemmatizer)
+                    .with_strategy(strategy),
+            ),
+            // Forms as found, not lemmas
+            Ok(vec!["Affen".into(), "Gruppen".into()])
+        );
+    }
+
+    #[test]
+    fn test_lemmatizer_for_valid_forms() {
+        let calls = RefCell::new(Vec::new());
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+        let lemmatizer = |w: &str| {
+            calls.borrow_mut().push(w.to_owned());
+            lemmatizer(w)
+        };
+
+        let constituents = decompound_spans(
+            "Kindergärten",
+            &is_valid_single_word,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_lemmatizer(&lemmatizer),
+        )
+        .unwrap();
+
+        assert_eq!(
+            constituents
+                .iter()
+                .map(|c| (c.form(), c.lemma()))
+                .collect::<Vec<_>>(),
+            vec![("Kinder", None), ("Gärten", Some("Garten"))]
+        );
+
+        let calls = calls.into_inner();
+        assert!(calls.contains(&"Gärten".to_owned()));
+        // Only final constituents
+        assert!(calls.iter().all(|w| w.to_lowercase().ends_with('n')));
+    }
+}
@@ -0,0 +1,200 @@
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use decompound::{
+        decompound_with_lexicons, DecompositionConfig as Config,
+        DecompositionError::{self, NothingValid, SingleWord},
+        DecompositionOptions as Opt, Lexicons, SearchStrategy,
+    };
+    use rstest::rstest;
+
+    fn general(w: &str) -> bool {
+        ["Bau", "Mast", "Baum", "Ast", "Raum", "Entwickler", "Ware"].contains(&w)
+    }
+
+    fn domain(w: &str) -> bool {
+        ["Baum", "Ast", "Software"].contains(&w)
+    }
+
+    fn loanwords(w: &str) -> bool {
+        ["Meeting", "Software", "Soft"].contains(&w)
+    }
+
+    #[rstest]
+    #[case("Meetingraum", 0, Ok(vec![("Meeting", "loanwords"), ("Raum", "general")]))]
+    // In order added for equal priorities
+    #[case("Softwareentwickler", 0, Ok(vec![("Software", "domain"), ("Entwickler", "general")]))]
+    #[case("Softwareentwickler", -1, Ok(vec![("Software", "loanwords"), ("Entwickler", "general")]))]
+    // Ties in the number of constituents are broken by priority ...
+    #[case("Baumast", 1, Ok(vec![("Baum", "domain"), ("Ast", "domain")]))]
+    // ... but otherwise, the first one found still wins.
+    #[case("Baumast", 0, Ok(vec![("Bau", "general"), ("Mast", "general")]))]
+    #[case("Baumast", -1, Ok(vec![("Bau", "general"), ("Mast", "general")]))]
+    #[case("Raum", 0, Err(SingleWord("Raum".into())))]
+    #[case("Meetingzimmer", 0, Err(NothingValid))]
+    fn test_decompound_with_lexicons(
+        #[case] word: &str,
+        #[case] domain_priority: i32,
+        #[case] expected: Result<Vec<(&str, &str)>, DecompositionError>,
+    ) {
+        let lexicons = Lexicons::new()
+            .with_lexicon("general", 0, &general)
+            .with_lexicon("domain", domain_priority, &domain)
+            .with_lexicon("loanwords", 0, &loanwords);
+
+        let result = decompound_with_lexicons(word, &lexicons, Opt::TRY_TITLECASE_SUFFIX);
+
+        match (result, expected) {
+            (Ok(constituents), Ok(expected)) => {
+                let found: Vec<(&str, &str)> = constituents
+                    .iter()
+                    .map(|c| (c.form(), c.lexicon().unwrap()))
+                    .collect();
+                assert_eq!(found, expected);
+            }
+            (result, expected) => assert_eq!(result.map(|_| ()), expected.map(|_| ())),
+        }
+    }
+
+    #[rstest]
+    #[case(vec![("a", 0), ("b", 0)], "a")]
+    #[case(vec![("b", 0), ("a", 0)], "b")]
+    #[case(vec![("a", 0), ("b", 1)], "b")]
+    #[case(vec![("a", -1), ("b", 0)], "b")]
+    #[case(vec![("a", 2), ("b", 1), ("c", 2)], "a")]
+    fn test_source(#[case] lexicons: Vec<(&str, i32)>, #[case] expected: &str) {
+        let contains_all = |_: &str| true;
+
+        let lexicons = lexicons
+            .into_iter()
+            .fold(Lexicons::new(), |lexicons, (name, priority)| {
+                lexicons.with_lexicon(name, priority, &contains_all)
+            });
+
+        assert_eq!(lexicons.source("Haus"), Some(expected));
+    }
+
+    #[test]
+    fn test_source_missing() {
+        assert_eq!(Lexicons::new().source("Haus"), None);
+    }
+
+    #[test]
+    fn test_priority_with_shatter() {
+        let lexicons = Lexicons::new()
+            .with_lexicon("general", 0, &general)
+            .with_lexicon("domain", 1, &domain);
+
+        let constituents = decompound_with_lexicons(
+            "Baumast",
+            &lexicons,
+            Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER,
+        )
+        .unwrap();
+
+        let forms: Vec<&str> = constituents.iter().map(|c| c.form()).collect();
+        assert_eq!(forms, vec!["Baum", "Ast"]);
+    }
+
+    #[test]
+    fn test_priority_ignored_with_scorer() {
+        let lexicons = Lexicons::new()
+            .with_lexicon("general", 0, &general)
+            .with_lexicon("domain", 1, &domain);
+        let prefer_bau = |forms: &[&str]| if forms.contains(&"Bau") { 1.0 } else { 0.0 };
+
+        let constituents = decompound_with_lexicons(
+            "Baumast",
+            &lexicons,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_scorer(&prefer_bau),
+        )
+        .unwrap();
+
+        let forms: Vec<&str> = constituents.iter().map(|c| c.form()).collect();
+        assert_eq!(forms, vec!["Bau", "Mast"]);
+    }
+
+    #[test]
+    fn test_source_of_lemmas() {
+        let lexicons = Lexicons::new()
+            .with_lexicon("general", 0, &general)
+            .with_lexicon("loanwords", 0, &loanwords);
+        let lemmatizer = |w: &str| w.strip_suffix('n').map(ToOwned::to_owned);
+
+        let constituents = decompound_with_lexicons(
+            "Softwaren",
+            &lexicons,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_lemmatizer(&lemmatizer),
+        )
+        .unwrap();
+
+        let found: Vec<(&str, Option<&str>, Option<&str>)> = constituents
+            .iter()
+            .map(|c| (c.form(), c.lemma(), c.lexicon()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("Soft", None, Some("loanwords")),
+                ("Waren", Some("Ware"), Some("general"))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_source_for_plain_decompound() {
+        let constituents =
+            decompound::decompound_spans("Baumast", &general, Opt::TRY_TITLECASE_SUFFIX).unwrap();
+
+        assert!(constituents.iter().all(|c| c.lexicon().is_none()));
+    }
+
+    #[test]
+    fn test_lexicons_called_once_per_form() {
+        let calls = RefCell::new(Vec::new());
+        let counting = |w: &str| {
+            calls.borrow_mut().push(w.to_owned());
+            general(w)
+        };
+        let lexicons = Lexicons::new().with_lexicon("general", 0, &counting);
+
+        let _ = decompound_with_lexicons("Baumast", &lexicons, Opt::TRY_TITLECASE_SUFFIX);
+
+        let mut calls = calls.into_inner();
+        let len = calls.len();
+        calls.sort();
+        calls.dedup();
+        assert_eq!(calls.len(), len);
+    }
+
+    #[rstest]
+    #[case(SearchStrategy::Exhaustive)]
+    #[case(SearchStrategy::GreedyLongestPrefix)]
+    #[case(SearchStrategy::GreedyLongestSuffix)]
+    #[case(SearchStrategy::Beam { width: 2 })]
+    fn test_lexicons_all_strategies(#[case] strategy: SearchStrategy) {
+        let lexicons = Lexicons::new()
+            .with_lexicon("general", 0, &general)
+            .with_lexicon("loanwords", 0, &loanwords);
+
+        let constituents = decompound_with_lexicons(
+            "Meetingraum",
+            &lexicons,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_strategy(strategy),
+        )
+        .unwrap();
+
+        let found: Vec<Option<&str>> = constituents.iter().map(|c| c.lexicon()).collect();
+        assert_eq!(found, vec![Some("loanwords"), Some("general")]);
+    }
+
+    #[test]
+    fn test_lexicons_debug() {
+        let lexicons = Lexicons::new()
+            .with_lexicon("general", 0, &general)
+            .with_lexicon("domain", 1, &domain);
+
+        assert_eq!(format!("{lexicons:?}"), r#"{"domain": 1, "general": 0}"#);
+    }
+}
@@ -0,0 +1,185 @@
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, collections::HashSet};
+
+    use decompound::{
+        decompound_morphemes, DecompositionConfig as Config,
+        DecompositionError::{self, NothingValid, SingleWord},
+        DecompositionOptions as Opt, MorphemeClass,
+        MorphemeClass::*,
+        Morphotactics,
+    };
+    use rstest::rstest;
+
+    fn is_valid_morpheme(m: &str, class: MorphemeClass) -> bool {
+        match class {
+            Prefix => ["un", "Un", "ver"].contains(&m),
+            Stem => [
+                "Freund",
+                "Spiel",
+                "Haus",
+                "Tür",
+                "kauf",
+                "Kauf",
+                "Schlüssel",
+            ]
+            .contains(&m),
+            Linker => ["s", "es", "n"].contains(&m),
+            Derivation => ["schaft", "lich", "keit", "ung", "er"].contains(&m),
+            Inflection => ["en", "es", "n", "s", "e"].contains(&m),
+        }
+    }
+
+    #[rstest]
+    #[case("Freundschaftsspiel", Opt::TRY_TITLECASE_SUFFIX, Ok(vec![("Freund", Stem), ("schaft", Derivation), ("s", Linker), ("Spiel", Stem)]))]
+    #[case("Unfreundlichkeit", Opt::empty(), Ok(vec![("Un", Prefix), ("freund", Stem), ("lich", Derivation), ("keit", Derivation)]))]
+    #[case("Haustürschlüssel", Opt::TRY_TITLECASE_SUFFIX, Ok(vec![("Haus", Stem), ("Tür", Stem), ("Schlüssel", Stem)]))]
+    #[case("Verkäufer", Opt::empty(), Err(NothingValid))]
+    #[case("Verkaufs", Opt::TRY_TITLECASE_SUFFIX, Err(NothingValid))]
+    // Inflections end words.
+    #[case("Spielenhaus", Opt::TRY_TITLECASE_SUFFIX, Err(NothingValid))]
+    #[case("Häuser", Opt::empty(), Err(NothingValid))]
+    #[case("Spiele", Opt::empty(), Ok(vec![("Spiel", Stem), ("e", Inflection)]))]
+    // Linkers cannot end words.
+    #[case("Freundes", Opt::empty(), Ok(vec![("Freund", Stem), ("es", Inflection)]))]
+    #[case("Haus", Opt::empty(), Err(SingleWord("Haus".into())))]
+    #[case("", Opt::empty(), Err(NothingValid))]
+    fn test_german(
+        #[case] word: &str,
+        #[case] options: Opt,
+        #[case] expected: Result<Vec<(&str, MorphemeClass)>, DecompositionError>,
+    ) {
+        let is_valid_morpheme =
+            |m: &str, class| is_valid_morpheme(m, class) || (class == Stem && m == "freund");
+
+        let result =
+            decompound_morphemes(word, &is_valid_morpheme, &Morphotactics::german(), options);
+
+        assert_eq!(
+            result.map(|morphemes| morphemes
+                .iter()
+                .map(|m| (m.form().to_owned(), m.class()))
+                .collect::<Vec<_>>()),
+            expected.map(|v| v.into_iter().map(|(f, c)| (f.to_owned(), c)).collect())
+        );
+    }
+
+    #[test]
+    fn test_shatter() {
+        let is_valid_morpheme = |m: &str, class| match class {
+            Stem => ["Haus", "Tür", "Haustür"].contains(&m),
+            _ => false,
+        };
+        let grammar = Morphotactics::german();
+
+        let forms = |options: Opt| -> Vec<String> {
+            decompound_morphemes(
+                "Haustürhaus",
+                &is_valid_morpheme,
+                &grammar,
+                Config::new(options),
+            )
+            .unwrap()
+            .iter()
+            .map(|m| m.form().to_owned())
+            .collect()
+        };
+
+        assert_eq!(forms(Opt::TRY_TITLECASE_SUFFIX), vec!["Haustür", "Haus"]);
+        assert_eq!(
+            forms(Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER),
+            vec!["Haus", "Tür", "Haus"]
+        );
+    }
+
+    #[test]
+    fn test_custom_grammar() {
+        // Finnish-like: a stem, then an ordered chain of derivation and inflections
+        let grammar = Morphotactics::new()
+            .with_initial(Stem)
+            .with_transition(Stem, Stem)
+            .with_transition(Stem, Derivation)
+            .with_transition(Stem, Inflection)
+            .with_transition(Derivation, Inflection)
+            .with_transition(Inflection, Inflection)
+            .with_final(Stem)
+            .with_final(Inflection);
+        let is_valid_morpheme = |m: &str, class| match class {
+            Stem => ["talo", "kirja"].contains(&m),
+            Derivation => ["sto"].contains(&m),
+            Inflection => ["ssa", "ni"].contains(&m),
+            _ => false,
+        };
+
+        let forms = |word: &str| -> Result<Vec<String>, DecompositionError> {
+            decompound_morphemes(word, &is_valid_morpheme, &grammar, Opt::empty())
+                .map(|ms| ms.iter().map(|m| m.form().to_owned()).collect())
+        };
+
+        assert_eq!(
+            forms("kirjastossani"),
+            Ok(vec![
+                "kirja".into(),
+                "sto".into(),
+                "ssa".into(),
+                "ni".into()
+            ])
+        );
+        assert_eq!(
+            forms("kirjatalossa"),
+            Ok(vec!["kirja".into(), "talo".into(), "ssa".into()])
+        );
+        // Derivations have to be inflected.
+        assert_eq!(forms("kirjasto"), Err(NothingValid));
+    }
+
+    #[test]
+    fn test_allows() {
+        let grammar = Morphotactics::german();
+
+        assert!(grammar.allows(&[Prefix, Stem, Derivation, Linker, Stem, Inflection]));
+        assert!(grammar.allows(&[Stem]));
+        assert!(!grammar.allows(&[]));
+        assert!(!grammar.allows(&[Stem, Linker]));
+        assert!(!grammar.allows(&[Linker, Stem]));
+        assert!(!grammar.allows(&[Stem, Inflection, Stem]));
+        assert!(!Morphotactics::new().allows(&[Stem]));
+    }
+
+    #[test]
+    fn test_validator_called_once_per_form_and_class() {
+        let calls = RefCell::new(Vec::new());
+        let is_valid_morpheme = |m: &str, class| {
+            calls.borrow_mut().push((m.to_owned(), class));
+            is_valid_morpheme(m, class)
+        };
+
+        let _ = decompound_morphemes(
+            "Spielspiel",
+            &is_valid_morpheme,
+            &Morphotactics::german(),
+            Opt::TRY_TITLECASE_SUFFIX,
+        );
+
+        let calls = calls.into_inner();
+        let distinct: HashSet<_> = calls.iter().collect();
+        assert!(!calls.is_empty());
+        assert_eq!(calls.len(), distinct.len());
+    }
+
+    #[test]
+    fn test_boundary_hints() {
+        let word = "Freund\u{00AD}schafts\u{00AD}spiel";
+
+        let morphemes = decompound_morphemes(
+            word,
+            &is_valid_morpheme,
+            &Morphotactics::german(),
+            Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS,
+        )
+        .unwrap();
+
+        let surfaces: Vec<&str> = morphemes.iter().map(|m| &word[m.span()]).collect();
+        assert_eq!(surfaces, vec!["Freund", "schaft", "s", "spiel"]);
+    }
+}
@@ -0,0 +1,227 @@
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use decompound::{
+        decompound, DecompositionConfig as Config, DecompositionOptions as Opt, SearchEvent,
+        SearchObserver, SearchStats,
+    };
+    use rstest::rstest;
+
+    const WORDS: &[&str] = &["Haus", "Boot", "Steg", "Wach", "Stube", "Wachs", "Tube"];
+
+    /// Records the name of every event, and the forms of selected splits.
+    #[derive(Default)]
+    struct Log {
+        events: RefCell<Vec<String>>,
+    }
+
+    impl SearchObserver for Log {
+        fn on_event(&self, event: &SearchEvent<'_>) {
+            let entry = match event {
+                SearchEvent::Started { word } => format!("Started {word}"),
+                SearchEvent::Finished { word } => format!("Finished {word}"),
+                SearchEvent::ValidatorCalled { .. } => "ValidatorCalled".to_owned(),
+                SearchEvent::CandidateChecked { .. } => "CandidateChecked".to_owned(),
+                SearchEvent::LemmaChecked {
+                    form, lemma, valid, ..
+                } => format!("LemmaChecked {form} {lemma} {valid}"),
+                SearchEvent::PrefixTried { form, .. } => format!("PrefixTried {form}"),
+                SearchEvent::PrefixAccepted { form, .. } => format!("PrefixAccepted {form}"),
+                SearchEvent::SuffixCandidate { form, .. } => format!("SuffixCandidate {form}"),
+                SearchEvent::SplitRecorded { .. } => "SplitRecorded".to_owned(),
+                SearchEvent::SplitSelected {
+                    constituents,
+                    depth,
+                } => {
+                    let forms: Vec<&str> = constituents.iter().map(|c| c.form()).collect();
+                    format!("SplitSelected {depth} {}", forms.join(" "))
+                }
+                _ => "Other".to_owned(),
+            };
+
+            self.events.borrow_mut().push(entry);
+        }
+    }
+
+    #[test]
+    fn test_event_sequence() {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+        let log = Log::default();
+
+        let result = decompound(
+            "Hausbootsteg",
+            &is_valid_single_word,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_observer(&log),
+        );
+        assert_eq!(
+            result,
+            Ok(vec!["Haus".into(), "Boot".into(), "Steg".into()])
+        );
+
+        let events = log.events.into_inner();
+        assert_eq!(
+            events.first().map(String::as_str),
+            Some("Started Hausbootsteg")
+        );
+        assert_eq!(
+            events.last().map(String::as_str),
+            Some("Finished Hausbootsteg")
+        );
+
+        for expected in [
+            "PrefixTried Haus",
+            "PrefixAccepted Haus",
+            "SuffixCandidate Bootsteg",
+            "SplitRecorded",
+            "SplitSelected 1 Boot Steg",
+            "SplitSelected 0 Haus Boot Steg",
+        ] {
+            assert!(
+                events.iter().any(|e| e == expected),
+                "missing '{expected}' in {events:?}"
+            );
+        }
+
+        // Invalid prefixes are tried, but never accepted.
+        assert!(events.iter().any(|e| e == "PrefixTried Hau"));
+        assert!(!events.iter().any(|e| e == "PrefixAccepted Hau"));
+    }
+
+    #[test]
+    fn test_events_per_hyphenated_segment() {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+        let log = Log::default();
+
+        let _ = decompound(
+            "Hausboot-Wachstube",
+            &is_valid_single_word,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED).with_observer(&log),
+        );
+
+        let events = log.events.into_inner();
+        let searches: Vec<&str> = events
+            .iter()
+            .filter(|e| e.starts_with("Started") || e.starts_with("Finished"))
+            .map(String::as_str)
+            .collect();
+        assert_eq!(
+            searches,
+            vec![
+                "Started Hausboot",
+                "Finished Hausboot",
+                "Started Wachstube",
+                "Finished Wachstube"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lemma_checked() {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+        let lemmatizer = |w: &str| (w == "Stegen").then(|| "Steg".to_owned());
+        let log = Log::default();
+
+        let _ = decompound(
+            "Hausstegen",
+            &is_valid_single_word,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX)
+                .with_lemmatizer(&lemmatizer)
+                .with_observer(&log),
+        );
+
+        let events = log.events.into_inner();
+        assert!(
+            events.iter().any(|e| e == "LemmaChecked Stegen Steg true"),
+            "{events:?}"
+        );
+    }
+
+    #[rstest]
+    #[case("Hausboot", Config::new(Opt::TRY_TITLECASE_SUFFIX), 1, 1)]
+    #[case("Hausbootsteg", Config::new(Opt::TRY_TITLECASE_SUFFIX), 2, 2)]
+    #[case("Wachstube", Config::new(Opt::TRY_TITLECASE_SUFFIX), 2, 1)]
+    // Prefixes of the suffix are still tried one level deeper.
+    #[case("Wachstube", Config::new(Opt::empty()), 0, 1)]
+    #[case("Hausboot", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_max_depth(0), 1, 0)]
+    fn test_stats_splits_and_depth(
+        #[case] word: &str,
+        #[case] config: Config,
+        #[case] candidate_splits: usize,
+        #[case] max_depth: usize,
+    ) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+        let stats = SearchStats::new();
+
+        let _ = decompound(word, &is_valid_single_word, config.with_observer(&stats));
+
+        assert_eq!(stats.candidate_splits(), candidate_splits);
+        assert_eq!(stats.max_depth(), max_depth);
+    }
+
+    #[test]
+    fn test_stats_validator_calls() {
+        let calls = RefCell::new(0);
+        let is_valid_single_word = |w: &str| {
+            *calls.borrow_mut() += 1;
+            WORDS.contains(&w)
+        };
+        let stats = SearchStats::new();
+
+        let _ = decompound(
+            "Hausbootsteg",
+            &is_valid_single_word,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_observer(&stats),
+        );
+
+        assert_eq!(stats.validator_calls(), calls.into_inner());
+        assert!(stats.distinct_substrings() > 0);
+        assert!(stats.validator_calls() >= stats.distinct_substrings());
+    }
+
+    #[rstest]
+    #[case("Haus", Config::new(Opt::TRY_TITLECASE_SUFFIX))]
+    #[case("Hausxboot", Config::new(Opt::TRY_TITLECASE_SUFFIX))]
+    #[case("Hausboot", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_min_word_len(20))]
+    #[case("Hausbootsteg", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_max_constituents(2))]
+    #[case("Haus-Boot", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED).with_max_constituents(1))]
+    #[case("Lehrer*innen", Config::new(Opt::GENDER_INCLUSIVE))]
+    fn test_stats_validator_calls_for_whole_word(#[case] word: &str, #[case] config: Config) {
+        let calls = std::cell::Cell::new(0);
+        let is_valid_single_word = |w: &str| {
+            calls.set(calls.get() + 1);
+            WORDS.contains(&w) || ["Lehrer", "Lehrerinnen"].contains(&w)
+        };
+        let stats = SearchStats::new();
+
+        let _ = decompound(word, &is_valid_single_word, config.with_observer(&stats));
+
+        assert!(calls.get() > 0);
+        assert_eq!(stats.validator_calls(), calls.get());
+    }
+
+    #[test]
+    fn test_stats_accumulate() {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+        let stats = SearchStats::new();
+        let config = Config::new(Opt::TRY_TITLECASE_SUFFIX).with_observer(&stats);
+
+        let _ = decompound("Hausboot", &is_valid_single_word, config.clone());
+        let once = stats.validator_calls();
+        let _ = decompound("Hausboot", &is_valid_single_word, config);
+
+        assert_eq!(stats.validator_calls(), 2 * once);
+        assert_eq!(stats.candidate_splits(), 2);
+    }
+
+    #[test]
+    fn test_stats_empty() {
+        let stats = SearchStats::new();
+
+        assert_eq!(stats.validator_calls(), 0);
+        assert_eq!(stats.distinct_substrings(), 0);
+        assert_eq!(stats.max_depth(), 0);
+        assert_eq!(stats.candidate_splits(), 0);
+        assert_eq!(stats.elapsed(), std::time::Duration::ZERO);
+    }
+}
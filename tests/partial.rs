@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use decompound::{
+        decompound_partial, DecompositionConfig as Config, DecompositionError::*,
+        DecompositionOptions as Opt, Exceptions,
+    };
+    use rstest::rstest;
+
+    const WORDS: &[&str] = &[
+        "Quanten", "Gerät", "Haus", "Boot", "Dampf", "Schiff", "Fahrt",
+    ];
+
+    #[rstest]
+    #[case("Quantenkryptografiegerät", Config::new(Opt::TRY_TITLECASE_SUFFIX), vec![(true, "Quanten", 0..7), (false, "kryptografie", 7..19), (true, "Gerät", 19..25)])]
+    // Complete decompositions are passed through
+    #[case("Hausboot", Config::new(Opt::TRY_TITLECASE_SUFFIX), vec![(true, "Haus", 0..4), (true, "Boot", 4..8)])]
+    // Valid single words, too
+    #[case("Haus", Config::new(Opt::empty()), vec![(true, "Haus", 0..4)])]
+    #[case("Xylofon", Config::new(Opt::empty()), vec![(false, "Xylofon", 0..7)])]
+    #[case("", Config::new(Opt::empty()), vec![])]
+    #[case("Hausxboot", Config::new(Opt::TRY_TITLECASE_SUFFIX), vec![(true, "Haus", 0..4), (false, "x", 4..5), (true, "Boot", 5..9)])]
+    #[case("xHaus", Config::new(Opt::TRY_TITLECASE_SUFFIX), vec![(false, "x", 0..1), (true, "Haus", 1..5)])]
+    #[case("Hausboot", Config::new(Opt::empty()), vec![(true, "Haus", 0..4), (false, "boot", 4..8)])]
+    // Fewest parts among those covering most
+    #[case("Dampfschifffahrtx", Config::new(Opt::TRY_TITLECASE_SUFFIX), vec![(true, "Dampf", 0..5), (true, "Schiff", 5..11), (true, "Fahrt", 11..16), (false, "x", 16..17)])]
+    #[case("Dampfschiffxfahrt", Config::new(Opt::TRY_TITLECASE_SUFFIX), vec![(true, "Dampf", 0..5), (true, "Schiff", 5..11), (false, "x", 11..12), (true, "Fahrt", 12..17)])]
+    #[case("Quantenkryptografiegerät", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_min_constituent_len(6), vec![(true, "Quanten", 0..7), (false, "kryptografiegerät", 7..25)])]
+    #[case("Dampfschifxfahrt", Config::new(Opt::TRY_TITLECASE_SUFFIX), vec![(true, "Dampf", 0..5), (false, "schifx", 5..11), (true, "Fahrt", 11..16)])]
+    #[case("Dampfschifxfahrt", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_min_constituent_len(6), vec![(false, "Dampfschifxfahrt", 0..16)])]
+    #[case("Quanten-kryptografie-Gerät", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED), vec![(true, "Quanten", 0..7), (false, "kryptografie", 8..20), (true, "Gerät", 21..27)])]
+    // Hints are not part of unknown stretches
+    #[case("Haus\u{00AD}x\u{00AD}boot", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS), vec![(true, "Haus", 0..4), (false, "x", 6..7), (true, "Boot", 9..13)])]
+    fn test_decompound_partial(
+        #[case] word: &str,
+        #[case] config: Config,
+        #[case] expected: Vec<(bool, &str, std::ops::Range<usize>)>,
+    ) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        let parts = decompound_partial(word, &is_valid_single_word, config).unwrap();
+
+        assert_eq!(
+            parts
+                .iter()
+                .map(|p| (p.is_known(), p.form(), p.constituent().span()))
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_decompound_partial_boundary_predicate() {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+        let not_before_fahrt = |_: &str, right: &str| !right.starts_with("fahrt");
+
+        let parts = decompound_partial(
+            "Dampfschifffahrtx",
+            &is_valid_single_word,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_boundary_predicate(&not_before_fahrt),
+        )
+        .unwrap();
+
+        assert_eq!(
+            parts
+                .iter()
+                .map(|p| (p.is_known(), p.form()))
+                .collect::<Vec<_>>(),
+            vec![(true, "Dampf"), (true, "Schiff"), (false, "fahrtx")]
+        );
+    }
+
+    #[test]
+    fn test_decompound_partial_fixed_split() {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+        let exceptions = Exceptions::new().with_fixed_split("Bootshaus", ["Boots", "Haus"]);
+
+        let parts = decompound_partial(
+            "Dampfxhausbootshaus",
+            &is_valid_single_word,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_exceptions(&exceptions),
+        )
+        .unwrap();
+
+        assert_eq!(
+            parts
+                .iter()
+                .map(|p| (p.is_known(), p.form(), p.constituent().span()))
+                .collect::<Vec<_>>(),
+            vec![
+                (true, "Dampf", 0..5),
+                (false, "x", 5..6),
+                (true, "Haus", 6..10),
+                (true, "Boots", 10..15),
+                (true, "Haus", 15..19)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decompound_partial_budget_exhausted() {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        assert_eq!(
+            decompound_partial(
+                "Quantenkryptografiegerät",
+                &is_valid_single_word,
+                Config::new(Opt::TRY_TITLECASE_SUFFIX).with_max_validator_calls(5),
+            ),
+            Err(BudgetExhausted(None))
+        );
+    }
+
+    #[rstest]
+    #[case(40)]
+    #[case(60)]
+    #[case(100)]
+    fn test_decompound_partial_budget_covers_whole_call(#[case] max: usize) {
+        let calls = Cell::new(0);
+        let is_valid_single_word = |w: &str| {
+            calls.set(calls.get() + 1);
+            WORDS.contains(&w)
+        };
+
+        let _ = decompound_partial(
+            "Hausbootxyz",
+            &is_valid_single_word,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_max_validator_calls(max),
+        );
+
+        let calls = calls.get();
+        assert!(calls <= max, "{calls} calls for a limit of {max}");
+    }
+}
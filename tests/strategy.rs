@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use decompound::{
+        decompound, DecompositionConfig as Config, DecompositionError, DecompositionError::*,
+        DecompositionOptions as Opt, Exceptions, SearchStrategy, SearchStrategy::*,
+    };
+    use rstest::rstest;
+
+    type DecompositionTestResult<'a> = Result<Vec<&'a str>, DecompositionError>;
+
+    const WORDS: &[&str] = &[
+        "Donau", "Dampf", "Schiff", "Fahrt", "Wach", "Wachs", "Stube", "Staub", "Sauger", "Staubs",
+        "Auger", "Ur", "Urin", "Instinkt", "stinkt", "Haus", "Boot",
+    ];
+
+    #[rstest]
+    #[case("Donaudampfschifffahrt", Exhaustive, Ok(vec!["Donau", "Dampf", "Schiff", "Fahrt"]))]
+    #[case("Donaudampfschifffahrt", GreedyLongestPrefix, Ok(vec!["Donau", "Dampf", "Schiff", "Fahrt"]))]
+    #[case("Donaudampfschifffahrt", GreedyLongestSuffix, Ok(vec!["Donau", "Dampf", "Schiff", "Fahrt"]))]
+    #[case("Donaudampfschifffahrt", Beam { width: 1 }, Ok(vec!["Donau", "Dampf", "Schiff", "Fahrt"]))]
+    //
+    #[case("Wachstube", Exhaustive, Ok(vec!["Wach", "Stube"]))]
+    #[case("Wachstube", GreedyLongestPrefix, Err(NothingValid))]
+    #[case("Wachstube", GreedyLongestSuffix, Ok(vec!["Wach", "Stube"]))]
+    #[case("Wachstube", Beam { width: 0 }, Err(NothingValid))]
+    #[case("Wachstube", Beam { width: 1 }, Err(NothingValid))]
+    #[case("Wachstube", Beam { width: 2 }, Ok(vec!["Wach", "Stube"]))]
+    //
+    #[case("Staubsauger", Exhaustive, Ok(vec!["Staub", "Sauger"]))]
+    #[case("Staubsauger", GreedyLongestPrefix, Ok(vec!["Staubs", "Auger"]))]
+    #[case("Staubsauger", GreedyLongestSuffix, Ok(vec!["Staub", "Sauger"]))]
+    #[case("Staubsauger", Beam { width: 1 }, Ok(vec!["Staubs", "Auger"]))]
+    #[case("Staubsauger", Beam { width: 2 }, Ok(vec!["Staub", "Sauger"]))]
+    //
+    #[case("Haus", GreedyLongestPrefix, Err(SingleWord("Haus".into())))]
+    #[case("Haus", GreedyLongestSuffix, Err(SingleWord("Haus".into())))]
+    #[case("Haus", Beam { width: 3 }, Err(SingleWord("Haus".into())))]
+    #[case("Hausxboot", GreedyLongestPrefix, Err(NothingValid))]
+    #[case("Hausxboot", GreedyLongestSuffix, Err(NothingValid))]
+    #[case("Hausxboot", Beam { width: 3 }, Err(NothingValid))]
+    fn test_strategies(
+        #[case] word: &str,
+        #[case] strategy: SearchStrategy,
+        #[case] expected: DecompositionTestResult,
+    ) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        assert_eq!(
+            decompound(
+                word,
+                &is_valid_single_word,
+                Config::new(Opt::TRY_TITLECASE_SUFFIX).with_strategy(strategy)
+            ),
+            expected.map(|v| v.into_iter().map(ToOwned::to_owned).collect())
+        );
+    }
+
+    #[rstest]
+    #[case(Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED), "Hausboot-Donaudampf", Ok(vec!["Haus", "Boot", "Donau", "Dampf"]))]
+    #[case(Config::new(Opt::TRY_TITLECASE_SUFFIX).with_max_constituents(3), "Donaudampfschifffahrt", Err(NothingValid))]
+    #[case(Config::new(Opt::TRY_TITLECASE_SUFFIX).with_max_depth(1), "Donaudampfschifffahrt", Err(NothingValid))]
+    #[case(Config::new(Opt::TRY_TITLECASE_SUFFIX).with_max_depth(1), "Donaudampfschiff", Ok(vec!["Donau", "Dampf", "Schiff"]))]
+    #[case(Config::new(Opt::empty()), "Hausboot", Err(NothingValid))]
+    fn test_strategies_respect_config(
+        #[values(GreedyLongestPrefix, GreedyLongestSuffix, Beam { width: 2 })]
+        strategy: SearchStrategy,
+        #[case] config: Config,
+        #[case] word: &str,
+        #[case] expected: DecompositionTestResult,
+    ) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        assert_eq!(
+            decompound(word, &is_valid_single_word, config.with_strategy(strategy)),
+            expected.map(|v| v.into_iter().map(ToOwned::to_owned).collect())
+        );
+    }
+
+    #[rstest]
+    #[case("Urinstinkt", Ok(vec!["Ur", "Instinkt"]))]
+    #[case("Hausurinstinkt", Ok(vec!["Haus", "Ur", "Instinkt"]))]
+    #[case("Hausboot", Err(SingleWord("Hausboot".into())))]
+    #[case("Donauhausboot", Ok(vec!["Donau", "Hausboot"]))]
+    fn test_strategies_respect_exceptions(
+        #[values(GreedyLongestPrefix, GreedyLongestSuffix, Beam { width: 2 })]
+        strategy: SearchStrategy,
+        #[case] word: &str,
+        #[case] expected: DecompositionTestResult,
+    ) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w) || w == "Hausboot";
+        let exceptions = Exceptions::new()
+            .with_fixed_split("Urinstinkt", ["Ur", "Instinkt"])
+            .with_undecomposable_word("Hausboot");
+
+        assert_eq!(
+            decompound(
+                word,
+                &is_valid_single_word,
+                Config::new(Opt::TRY_TITLECASE_SUFFIX)
+                    .with_strategy(strategy)
+                    .with_exceptions(&exceptions)
+            ),
+            expected.map(|v| v.into_iter().map(ToOwned::to_owned).collect())
+        );
+    }
+}
@@ -0,0 +1,161 @@
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use decompound::{
+        decompound, decompound_spans, titlecase, DecompositionConfig as Config,
+        DecompositionError::NothingValid, DecompositionOptions as Opt, Forms, Lattice, Position,
+        SearchStrategy,
+    };
+    use rstest::rstest;
+
+    fn lowercase_modifiers(w: &str, position: Position) -> Forms<'_> {
+        if position == Position::Final {
+            Box::new(std::iter::empty())
+        } else {
+            Box::new(std::iter::once(Cow::Owned(w.to_lowercase())))
+        }
+    }
+
+    fn remove_umlauts(w: &str, _: Position) -> Forms<'_> {
+        let replaced = w.replace('ä', "a").replace('ö', "o").replace('ü', "u");
+        Box::new(std::iter::once(Cow::Owned(replaced)))
+    }
+
+    fn remove_apostrophes(w: &str, _: Position) -> Forms<'_> {
+        Box::new(std::iter::once(Cow::Owned(w.replace('\'', ""))))
+    }
+
+    #[rstest]
+    #[case("boot", Position::Final, vec!["Boot"])]
+    #[case("boot", Position::Medial, vec!["Boot"])]
+    #[case("boot", Position::Initial, vec![])]
+    #[case("BOOT", Position::Final, vec!["Boot"])]
+    #[case("ßtube", Position::Final, vec!["Sstube"])]
+    fn test_titlecase(
+        #[case] surface: &str,
+        #[case] position: Position,
+        #[case] expected: Vec<&str>,
+    ) {
+        assert_eq!(titlecase(surface, position).collect::<Vec<_>>(), expected);
+    }
+
+    #[rstest]
+    #[case("Rotwein", Config::new(Opt::TRY_TITLECASE_SUFFIX), Err(NothingValid))]
+    #[case("Rotwein", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_transform(&lowercase_modifiers), Ok(vec!["rot", "Wein"]))]
+    // Titlecasing still needed for the head
+    #[case("Rotwein", Config::new(Opt::empty()).with_transform(&lowercase_modifiers), Err(NothingValid))]
+    // Medial constituents are transformed, too
+    #[case("Weißrotwein", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_transform(&lowercase_modifiers), Ok(vec!["weiß", "rot", "Wein"]))]
+    #[case(
+        "Mäusefalle",
+        Config::new(Opt::TRY_TITLECASE_SUFFIX),
+        Err(NothingValid)
+    )]
+    #[case("Mäusefalle", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_transform(&remove_umlauts), Ok(vec!["Mause", "Falle"]))]
+    #[case("Rock'n'Roll", Config::new(Opt::empty()).with_transform(&remove_apostrophes), Ok(vec!["Rockn", "Roll"]))]
+    // Transforms add up, but apply to constituents as is, not to each other's forms
+    #[case("Rotwein-Mäusefalle", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SPLIT_HYPHENATED).with_transform(&lowercase_modifiers).with_transform(&remove_umlauts), Ok(vec!["rot", "Wein", "Mause", "Falle"]))]
+    #[case("Rotmäusefalle", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_transform(&lowercase_modifiers).with_transform(&remove_umlauts), Err(NothingValid))]
+    fn test_transforms(
+        #[case] word: &str,
+        #[case] config: Config,
+        #[case] expected: Result<Vec<&str>, decompound::DecompositionError>,
+    ) {
+        let is_valid_single_word =
+            |w: &str| ["rot", "weiß", "Wein", "Mause", "Falle", "Rockn", "Roll"].contains(&w);
+
+        assert_eq!(
+            decompound(word, &is_valid_single_word, config),
+            expected.map(|v| v.into_iter().map(ToOwned::to_owned).collect())
+        );
+    }
+
+    #[rstest]
+    #[case(SearchStrategy::Exhaustive)]
+    #[case(SearchStrategy::GreedyLongestPrefix)]
+    #[case(SearchStrategy::GreedyLongestSuffix)]
+    #[case(SearchStrategy::Beam { width: 2 })]
+    fn test_transforms_all_strategies(#[case] strategy: SearchStrategy) {
+        let is_valid_single_word = |w: &str| ["rot", "Wein"].contains(&w);
+
+        assert_eq!(
+            decompound(
+                "Rotwein",
+                &is_valid_single_word,
+                Config::new(Opt::TRY_TITLECASE_SUFFIX)
+                    .with_transform(&lowercase_modifiers)
+                    .with_strategy(strategy),
+            ),
+            Ok(vec!["rot".into(), "Wein".into()])
+        );
+    }
+
+    #[test]
+    fn test_transform_closure() {
+        let is_valid_single_word = |w: &str| ["Kinder", "Garten"].contains(&w);
+        let suffix = String::from("s");
+
+        let constituents = decompound_spans(
+            "Kindergartens",
+            &is_valid_single_word,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_transform(&|w, position| match w
+                .strip_suffix(suffix.as_str())
+            {
+                Some(stem) if position == Position::Final => {
+                    Box::new(std::iter::once(Cow::Borrowed(stem)).chain(titlecase(stem, position)))
+                }
+                _ => Box::new(std::iter::empty()),
+            }),
+        )
+        .unwrap();
+
+        let forms: Vec<&str> = constituents.iter().map(|c| c.form()).collect();
+        assert_eq!(forms, vec!["Kinder", "Garten"]);
+        assert_eq!(constituents[1].span(), 6..13);
+    }
+
+    #[test]
+    fn test_transform_positions() {
+        let seen = std::cell::RefCell::new(Vec::new());
+        let is_valid_single_word = |w: &str| ["a", "b", "c"].contains(&w);
+
+        let _ = decompound(
+            "abc",
+            &is_valid_single_word,
+            Config::new(Opt::empty()).with_transform(&|w, position| {
+                seen.borrow_mut().push((w.to_owned(), position));
+                Box::new(std::iter::empty())
+            }),
+        );
+
+        let seen = seen.into_inner();
+        assert!(seen.contains(&("a".to_owned(), Position::Initial)));
+        assert!(seen.contains(&("ab".to_owned(), Position::Initial)));
+        assert!(seen.contains(&("b".to_owned(), Position::Medial)));
+        assert!(seen.contains(&("bc".to_owned(), Position::Final)));
+        assert!(seen.contains(&("c".to_owned(), Position::Final)));
+    }
+
+    #[test]
+    fn test_transform_lattice() {
+        let is_valid_single_word = |w: &str| ["rot", "Wein"].contains(&w);
+
+        let lattice = Lattice::new(
+            "Rotwein",
+            &is_valid_single_word,
+            Config::new(Opt::TRY_TITLECASE_SUFFIX).with_transform(&lowercase_modifiers),
+        );
+
+        let forms: Vec<&str> = lattice.edges().iter().map(|e| e.form()).collect();
+        assert_eq!(forms, vec!["rot", "Wein"]);
+        assert!(lattice.edges().iter().all(|e| e.is_transformed()));
+    }
+
+    #[test]
+    fn test_transform_debug() {
+        let config = Config::default().with_transform(&titlecase);
+
+        assert!(format!("{config:?}").contains("transforms: [\"<transform>\"]"));
+    }
+}
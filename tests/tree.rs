@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod tests {
+    use decompound::{
+        decompound, decompound_tree, Bracketing, DecompositionConfig as Config,
+        DecompositionError::*, DecompositionOptions as Opt, Headedness, SearchStats,
+    };
+    use rstest::rstest;
+
+    const WORDS: &[&str] = &[
+        "Donau",
+        "Dampf",
+        "Schiff",
+        "Fahrt",
+        "Gesellschaft",
+        "Haus",
+        "Boot",
+        "Tür",
+        "Haustür",
+        "bed",
+        "room",
+        "Steg",
+    ];
+
+    #[rstest]
+    #[case("Hausboot", Config::new(Opt::TRY_TITLECASE_SUFFIX), "Haus Boot")]
+    #[case(
+        "Donaudampfschiff",
+        Config::new(Opt::TRY_TITLECASE_SUFFIX),
+        "Donau (Dampf Schiff)"
+    )]
+    #[case(
+        "Donaudampfschifffahrtgesellschaft",
+        Config::new(Opt::TRY_TITLECASE_SUFFIX),
+        "Donau (Dampf (Schiff (Fahrt Gesellschaft)))"
+    )]
+    // Without any evidence, the head is split off first
+    #[case("Donaudampfschiff", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_bracketing(Bracketing::LexiconEvidence), "(Donau Dampf) Schiff")]
+    #[case("Donaudampfschiff", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_bracketing(Bracketing::LexiconEvidence).with_headedness(Headedness::Left), "Donau (Dampf Schiff)")]
+    // Evidence overrides headedness
+    #[case("Haustürboot", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER).with_bracketing(Bracketing::LexiconEvidence), "(Haus Tür) Boot")]
+    #[case("Haustürboot", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER).with_bracketing(Bracketing::LexiconEvidence).with_headedness(Headedness::Left), "(Haus Tür) Boot")]
+    #[case("Boothaustür", Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER).with_bracketing(Bracketing::LexiconEvidence), "Boot (Haus Tür)")]
+    // Constituents which are compounds themselves are expanded
+    #[case("Haustürboot", Config::new(Opt::TRY_TITLECASE_SUFFIX), "Haustür Boot")]
+    #[case("Haustürboot", Config::new(Opt::TRY_TITLECASE_SUFFIX).with_bracketing(Bracketing::LexiconEvidence), "(Haus Tür) Boot")]
+    // Hyphen-separated parts are bracketed on their own
+    #[case("bedroom-Hausboot", Config::new(Opt::SPLIT_HYPHENATED | Opt::TRY_TITLECASE_SUFFIX), "(bed room) (Haus Boot)")]
+    #[case("bedroom-Hausboot", Config::new(Opt::SPLIT_HYPHENATED | Opt::TRY_TITLECASE_SUFFIX).with_bracketing(Bracketing::LexiconEvidence), "(bed room) (Haus Boot)")]
+    #[case("Bootsteg-Haus", Config::new(Opt::SPLIT_HYPHENATED | Opt::TRY_TITLECASE_SUFFIX), "(Boot Steg) Haus")]
+    #[case("Haus-Bootsteg", Config::new(Opt::SPLIT_HYPHENATED | Opt::TRY_TITLECASE_SUFFIX).with_bracketing(Bracketing::LexiconEvidence), "Haus (Boot Steg)")]
+    #[case("Haus-Boot-Steg", Config::new(Opt::SPLIT_HYPHENATED | Opt::TRY_TITLECASE_SUFFIX), "Haus (Boot Steg)")]
+    fn test_decompound_tree(#[case] word: &str, #[case] config: Config, #[case] expected: &str) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        let tree = decompound_tree(word, &is_valid_single_word, config).unwrap();
+
+        assert_eq!(tree.to_string(), expected);
+        assert_eq!(tree.span(), 0..word.len());
+    }
+
+    #[rstest]
+    #[case(Bracketing::Search)]
+    #[case(Bracketing::LexiconEvidence)]
+    fn test_tree_leaves_cover_word(#[case] bracketing: Bracketing) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+        let word = "Donaudampfschifffahrt\u{00AD}gesellschaft";
+        let config = Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::BOUNDARY_HINTS)
+            .with_bracketing(bracketing);
+
+        let tree = decompound_tree(word, &is_valid_single_word, config.clone()).unwrap();
+        let leaves = tree.leaves();
+
+        assert_eq!(
+            leaves.iter().map(|c| c.form()).collect::<Vec<_>>(),
+            decompound(word, &is_valid_single_word, config).unwrap()
+        );
+        assert_eq!(&word[leaves[2].span()], "schiff");
+        assert_eq!(&word[leaves[4].span()], "gesellschaft");
+    }
+
+    #[test]
+    fn test_decompound_tree_budget() {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+        let config = Config::new(Opt::TRY_TITLECASE_SUFFIX | Opt::SHATTER)
+            .with_bracketing(Bracketing::LexiconEvidence);
+
+        let searched = SearchStats::new();
+        let _ = decompound(
+            "Haustürboot",
+            &is_valid_single_word,
+            config.clone().with_observer(&searched),
+        );
+
+        // Evidence is looked up like constituents.
+        let bracketed = SearchStats::new();
+        let _ = decompound_tree(
+            "Haustürboot",
+            &is_valid_single_word,
+            config.clone().with_observer(&bracketed),
+        );
+        assert!(bracketed.validator_calls() > searched.validator_calls());
+
+        assert_eq!(
+            decompound_tree(
+                "Haustürboot",
+                &is_valid_single_word,
+                config.with_max_validator_calls(searched.validator_calls()),
+            ),
+            Err(BudgetExhausted(Some(vec![
+                "Haus".into(),
+                "Tür".into(),
+                "Boot".into()
+            ])))
+        );
+    }
+
+    #[rstest]
+    #[case("Haus", SingleWord("Haus".into()))]
+    #[case("Hausxboot", NothingValid)]
+    fn test_decompound_tree_errors(
+        #[case] word: &str,
+        #[case] expected: decompound::DecompositionError,
+    ) {
+        let is_valid_single_word = |w: &str| WORDS.contains(&w);
+
+        assert_eq!(
+            decompound_tree(word, &is_valid_single_word, Opt::TRY_TITLECASE_SUFFIX),
+            Err(expected)
+        );
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use decompound::{
+        mark_boundaries, mark_boundaries_with, BoundaryMark, DecompositionError,
+        DecompositionError::*, DecompositionOptions as Opt,
+    };
+    use rstest::rstest;
+
+    const WORDS: &[&str] = &["Donau", "Dampf", "Schiff", "Fahrt", "Haus", "Boot"];
+
+    #[rstest]
+    #[case(
+        "Donaudampfschiff",
+        Opt::TRY_TITLECASE_SUFFIX,
+        BoundaryMark::SoftHyphen,
+        Ok("Donau\u{00AD}dampf\u{00AD}schiff")
+    )]
+    #[case(
+        "Donaudampfschiff",
+        Opt::TRY_TITLECASE_SUFFIX,
+        BoundaryMark::MiddleDot,
+        Ok("Donau·dampf·schiff")
+    )]
+    #[case(
+        "Hausboot",
+        Opt::TRY_TITLECASE_SUFFIX,
+        BoundaryMark::MiddleDot,
+        Ok("Haus·boot")
+    )]
+    #[case("Hausboot", Opt::empty(), BoundaryMark::MiddleDot, Err(NothingValid))]
+    //
+    // Single words are left alone
+    #[case("Haus", Opt::empty(), BoundaryMark::SoftHyphen, Ok("Haus"))]
+    #[case("Haus", Opt::empty(), BoundaryMark::MiddleDot, Ok("Haus"))]
+    #[case("Hütte", Opt::empty(), BoundaryMark::MiddleDot, Err(NothingValid))]
+    //
+    // Hyphens are break opportunities already
+    #[case(
+        "Hausboot-Fahrt",
+        Opt::all(),
+        BoundaryMark::SoftHyphen,
+        Ok("Haus\u{00AD}boot-Fahrt")
+    )]
+    #[case(
+        "Hausboot-Fahrt",
+        Opt::all(),
+        BoundaryMark::MiddleDot,
+        Ok("Haus·boot-Fahrt")
+    )]
+    //
+    // Existing boundary hints are kept, and not doubled up
+    #[case(
+        "Haus\u{00AD}boot",
+        Opt::all(),
+        BoundaryMark::SoftHyphen,
+        Ok("Haus\u{00AD}boot")
+    )]
+    #[case(
+        "Haus\u{00AD}boot",
+        Opt::all(),
+        BoundaryMark::MiddleDot,
+        Ok("Haus\u{00AD}·boot")
+    )]
+    fn test_mark_boundaries(
+        #[case] word: &str,
+        #[case] options: Opt,
+        #[case] mark: BoundaryMark,
+        #[case] expected: Result<&str, DecompositionError>,
+    ) {
+        assert_eq!(
+            mark_boundaries(word, &|w| WORDS.contains(&w), options, mark),
+            expected.map(String::from)
+        );
+    }
+
+    #[rstest]
+    #[case(
+        "Donaudampfschiff",
+        BoundaryMark::SoftHyphen,
+        "Do\u{00AD}nau\u{00AD}dampf\u{00AD}schiff"
+    )]
+    #[case(
+        "Donaudampfschiff",
+        BoundaryMark::MiddleDot,
+        "Do\u{00AD}nau·dampf·schiff"
+    )]
+    #[case("Donau", BoundaryMark::MiddleDot, "Do\u{00AD}nau")]
+    #[case("Schifffahrt", BoundaryMark::SoftHyphen, "Schiff\u{00AD}fahrt")]
+    fn test_mark_boundaries_with_hyphenator(
+        #[case] word: &str,
+        #[case] mark: BoundaryMark,
+        #[case] expected: &str,
+    ) {
+        // Offsets out of bounds, at the edges or within chars are ignored.
+        let hyphenate = |constituent: &str| match constituent {
+            "Donau" => vec![2, 2, 0, 5, 99],
+            "Schiff" => vec![6],
+            "fahrt" => vec![0],
+            _ => vec![],
+        };
+
+        assert_eq!(
+            mark_boundaries_with(
+                word,
+                &|w| WORDS.contains(&w),
+                Opt::TRY_TITLECASE_SUFFIX,
+                mark,
+                hyphenate
+            )
+            .unwrap(),
+            expected
+        );
+    }
+}